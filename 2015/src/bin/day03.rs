@@ -1,5 +1,5 @@
 use advent_lib::grid::Infinite2dGrid;
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::Result;
 
 type Parsed = Vec<char>;
@@ -42,11 +42,14 @@ fn solve(dirs: &Parsed, with_robo_santa: bool) -> usize {
 
 struct Solver {}
 impl Day<Parsed, usize, usize> for Solver {
+  const PARSE_ONCE: bool = true;
+
   fn parse(
     &self,
     lines: Vec<String>,
     _sample_name: Option<String>,
     _for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     Ok(lines[0].chars().collect::<Vec<char>>())
   }
@@ -60,6 +63,4 @@ impl Day<Parsed, usize, usize> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2015, 3)
-}
+advent_lib::solution!(Solver {}, 2015, 3);
@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::Result;
 use crypto::digest::Digest;
 use crypto::md5::Md5;
@@ -20,11 +20,14 @@ fn solve(input: String, start_pattern: String) -> u64 {
 
 struct Solver {}
 impl Day<Parsed, u64, u64> for Solver {
+  const PARSE_ONCE: bool = true;
+
   fn parse(
     &self,
     lines: Vec<String>,
     _sample_name: Option<String>,
     _for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     Ok(lines[0].to_string())
   }
@@ -38,6 +41,4 @@ impl Day<Parsed, u64, u64> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2015, 4)
-}
+advent_lib::solution!(Solver {}, 2015, 4);
@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::Result;
 
 type P1Out = u64;
@@ -7,11 +7,14 @@ type Parsed = Vec<String>;
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
+  const PARSE_ONCE: bool = true;
+
   fn parse(
     &self,
     lines: Vec<String>,
     _sample_name: Option<String>,
     _for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     Ok(lines)
   }
@@ -82,6 +85,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2015, 5)
-}
+advent_lib::solution!(Solver {}, 2015, 5);
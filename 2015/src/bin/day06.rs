@@ -1,7 +1,7 @@
 use advent_lib::grid::{Infinite2dGrid, Infinite2dSet};
 use regex::Regex;
 
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Context, Result};
 
 type P1Out = u64;
@@ -27,11 +27,14 @@ type Parsed = Vec<Command>;
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
+  const PARSE_ONCE: bool = true;
+
   fn parse(
     &self,
     lines: Vec<String>,
     _sample_name: Option<String>,
     _for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     let parse_re = Regex::new(
       r"^(?P<kind>turn on|turn off|toggle) (?P<x1>\d+),(?P<y1>\d+) through (?P<x2>\d+),(?P<y2>\d+)$",
@@ -129,6 +132,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2015, 6)
-}
+advent_lib::solution!(Solver {}, 2015, 6);
@@ -1,8 +1,9 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Context, Result};
 use fnv::FnvBuildHasher;
 use regex::Regex;
 use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
 
 type P1Out = u16;
 type P2Out = u16;
@@ -78,11 +79,16 @@ fn simulate(commands: &Parsed, preset_b: Option<u16>) -> Result<Registers> {
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
+  const PARSE_ONCE: bool = true;
+  // A wire that nothing ever drives gets re-queued forever, where a good circuit takes milliseconds.
+  const TIME_LIMIT: Duration = Duration::from_secs(5);
+
   fn parse(
     &self,
     lines: Vec<String>,
     _sample_name: Option<String>,
     _for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     let re = Regex::new(r"^((?<a>[a-z\d]+) )?((?<op>[A-Z]+) )?(?<b>[a-z\d]+) -> (?<c>[a-z]+)$")
       .context("Bad parsing regex")?;
@@ -146,6 +152,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2015, 7)
-}
+advent_lib::solution!(Solver {}, 2015, 7);
@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Context, Result};
 use petgraph::graph::{NodeIndex, UnGraph};
 use regex::Regex;
@@ -76,11 +76,14 @@ fn solve(graph: &Parsed, max_instead: bool) -> Result<u64> {
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
+  const PARSE_ONCE: bool = true;
+
   fn parse(
     &self,
     lines: Vec<String>,
     _sample_name: Option<String>,
     _for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     let re = Regex::new(r"(?<a>[^\s]+) to (?<b>[^\s]+) = (?<cost>\d+)")?;
     let mut graph = UnGraph::<String, u64>::new_undirected();
@@ -121,6 +124,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2015, 9)
-}
+advent_lib::solution!(Solver {}, 2015, 9);
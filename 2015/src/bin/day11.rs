@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::Result;
 
 type P1Out = String;
@@ -90,6 +90,8 @@ fn find_next_pass(old: &Parsed) -> u64 {
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
+  const PARSE_ONCE: bool = true;
+
   /// 8 characters in ASCII just so happens to fit perfectly into a u64.
   /// That means we can do bit twiddling instead of string manipulation,
   /// which will run _much_ faster!
@@ -98,6 +100,7 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
     lines: Vec<String>,
     _sample_name: Option<String>,
     _for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     let bytes = lines[0].as_bytes();
     assert_eq!(bytes.len(), 8);
@@ -134,6 +137,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2015, 11)
-}
+advent_lib::solution!(Solver {}, 2015, 11);
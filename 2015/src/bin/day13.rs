@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Context, Result};
 use petgraph::graph::{DiGraph, NodeIndex};
 use regex::Regex;
@@ -83,6 +83,7 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
     lines: Vec<String>,
     _sample_name: Option<String>,
     for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     let re = Regex::new(
       r"^(?<a>[^\s]+) would (?<mode>gain|lose) (?<amount>\d+) happiness units by sitting next to (?<b>.+)\.$",
@@ -143,6 +144,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2015, 13)
-}
+advent_lib::solution!(Solver {}, 2015, 13);
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::Result;
 use regex::Regex;
 
@@ -39,11 +39,14 @@ fn who_leads(reindeer: &Vec<Reindeer>, race_time: u64) -> (Vec<&str>, u64) {
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
+  const PARSE_ONCE: bool = true;
+
   fn parse(
     &self,
     lines: Vec<String>,
     _sample_name: Option<String>,
     _for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     let re = Regex::new(r"^(?<name>[a-zA-Z]+) can fly (?<speed>\d+) km/s for (?<sprint_time>\d+) seconds, but then must rest for (?<rest_time>\d+) seconds.$").unwrap();
     Ok(
@@ -97,6 +100,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2015, 14)
-}
+advent_lib::solution!(Solver {}, 2015, 14);
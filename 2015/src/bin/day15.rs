@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::Result;
 use regex::Regex;
 
@@ -33,11 +33,14 @@ fn solve(ingredients: &Parsed, exact_calories: Option<i32>) -> Result<u64> {
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
+  const PARSE_ONCE: bool = true;
+
   fn parse(
     &self,
     lines: Vec<String>,
     _sample_name: Option<String>,
     _for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     let re = Regex::new(r"[-\d]+$")?;
     Ok(
@@ -73,6 +76,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2015, 15)
-}
+advent_lib::solution!(Solver {}, 2015, 15);
@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::Result;
 use fnv::{FnvBuildHasher, FnvHashMap};
 
@@ -10,11 +10,14 @@ type Sue = FnvHashMap<String, u8>;
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
+  const PARSE_ONCE: bool = true;
+
   fn parse(
     &self,
     lines: Vec<String>,
     sample_name: Option<String>,
     _for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     if sample_name.is_some() {
       return Ok(vec![]);
@@ -89,6 +92,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2015, 16)
-}
+advent_lib::solution!(Solver {}, 2015, 16);
@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::Result;
 use fnv::{FnvBuildHasher, FnvHashSet};
 
@@ -33,11 +33,14 @@ fn count_neighbors(grid: &FnvHashSet<(i32, i32)>, x: i32, y: i32, w: i32, h: i32
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
+  const PARSE_ONCE: bool = true;
+
   fn parse(
     &self,
     lines: Vec<String>,
     _sample_name: Option<String>,
     _for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     let h = lines.len() as i32;
     let w = lines[0].len() as i32;
@@ -110,6 +113,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2015, 18)
-}
+advent_lib::solution!(Solver {}, 2015, 18);
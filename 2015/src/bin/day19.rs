@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Result, bail};
 use fnv::{FnvBuildHasher, FnvHashMap, FnvHashSet};
 
@@ -8,11 +8,14 @@ type Parsed = (String, FnvHashMap<String, Vec<String>>);
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
+  const PARSE_ONCE: bool = true;
+
   fn parse(
     &self,
     lines: Vec<String>,
     _sample_name: Option<String>,
     _for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     let molecule = &lines[lines.len() - 1];
     let mut transforms: FnvHashMap<String, Vec<String>> =
@@ -103,6 +106,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2015, 19)
-}
+advent_lib::solution!(Solver {}, 2015, 19);
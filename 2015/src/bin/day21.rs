@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::Result;
 
 type P1Out = u16;
@@ -102,11 +102,14 @@ fn costliest_purchase(target_damage: u16, target_armor: u16) -> u16 {
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
+  const PARSE_ONCE: bool = true;
+
   fn parse(
     &self,
     lines: Vec<String>,
     sample_name: Option<String>,
     _for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     Ok(Parsed {
       player_hp: if sample_name.is_some() { 8 } else { 100 },
@@ -194,6 +197,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2015, 21)
-}
+advent_lib::solution!(Solver {}, 2015, 21);
@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::Result;
 
 type P1Out = u64;
@@ -222,7 +222,15 @@ fn solve(
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, sample_name: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(
+    &self,
+    lines: Vec<String>,
+    sample_name: Option<String>,
+    _: PartId,
+    _: &Params,
+  ) -> Result<Parsed> {
     Ok(Parsed {
       player_hp: if sample_name.is_some() { 10 } else { 50 },
       player_mana: if sample_name.is_some() { 250 } else { 500 },
@@ -240,6 +248,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2015, 22)
-}
+advent_lib::solution!(Solver {}, 2015, 22);
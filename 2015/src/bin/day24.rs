@@ -1,6 +1,6 @@
 use std::cmp::Ordering;
 
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::Result;
 
 type P1Out = u64;
@@ -87,11 +87,14 @@ fn solve(boxes: &Parsed, num_groups: u16) -> Result<u64> {
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
+  const PARSE_ONCE: bool = true;
+
   fn parse(
     &self,
     lines: Vec<String>,
     _sample_name: Option<String>,
     _for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     // Assumptions about the input, used to make this solution simpler and faster:
     // - The input is a list of unique, positive integers, sorted smallest to largest
@@ -113,6 +116,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2015, 24)
-}
+advent_lib::solution!(Solver {}, 2015, 24);
@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId, skip};
 use anyhow::Result;
 use regex::Regex;
 
@@ -8,11 +8,14 @@ type Parsed = (u64, u64);
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
+  const PARSE_ONCE: bool = true;
+
   fn parse(
     &self,
     lines: Vec<String>,
     _sample_name: Option<String>,
     _for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     let re = Regex::new(r"Enter the code at row ([\d]+), column ([\d]+).$").unwrap();
     let caps = re.captures(lines[0].as_str()).unwrap();
@@ -34,10 +37,8 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
 
   fn part2(&self, _lines: &Parsed, _sample_name: Option<String>) -> Result<P2Out> {
     // On day 25, there is no part 2. This star is granted by earning the other 49.
-    Ok(0)
+    skip("There is no part 2 on day 25")
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2015, 25)
-}
+advent_lib::solution!(Solver {}, 2015, 25);
@@ -0,0 +1,29 @@
+#[cfg(feature = "registry")]
+advent_lib::register_days!(
+  2015,
+  day01 = "bin/day01.rs",
+  day02 = "bin/day02.rs",
+  day03 = "bin/day03.rs",
+  day04 = "bin/day04.rs",
+  day05 = "bin/day05.rs",
+  day06 = "bin/day06.rs",
+  day07 = "bin/day07.rs",
+  day08 = "bin/day08.rs",
+  day09 = "bin/day09.rs",
+  day10 = "bin/day10.rs",
+  day11 = "bin/day11.rs",
+  day12 = "bin/day12.rs",
+  day13 = "bin/day13.rs",
+  day14 = "bin/day14.rs",
+  day15 = "bin/day15.rs",
+  day16 = "bin/day16.rs",
+  day17 = "bin/day17.rs",
+  day18 = "bin/day18.rs",
+  day19 = "bin/day19.rs",
+  day20 = "bin/day20.rs",
+  day21 = "bin/day21.rs",
+  day22 = "bin/day22.rs",
+  day23 = "bin/day23.rs",
+  day24 = "bin/day24.rs",
+  day25 = "bin/day25.rs",
+);
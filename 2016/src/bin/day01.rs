@@ -1,6 +1,6 @@
 use advent_lib::direction::{CardinalDirection, Rotation};
 use advent_lib::grid::Infinite2dSet;
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Result, bail};
 
 type P1Out = u64;
@@ -9,11 +9,14 @@ type Parsed = Vec<(Rotation, u8)>;
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
+  const PARSE_ONCE: bool = true;
+
   fn parse(
     &self,
     lines: Vec<String>,
     _sample_name: Option<String>,
     _for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     Ok(
       lines[0]
@@ -72,6 +75,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2016, 1)
-}
+advent_lib::solution!(Solver {}, 2016, 1);
@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::Result;
 use crypto::digest::Digest;
 use crypto::md5::Md5;
@@ -7,11 +7,14 @@ type Parsed = String;
 
 struct Solver {}
 impl Day<Parsed, String, String> for Solver {
+  const PARSE_ONCE: bool = true;
+
   fn parse(
     &self,
     lines: Vec<String>,
     _sample_name: Option<String>,
     _for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     Ok(lines[0].to_string())
   }
@@ -58,6 +61,4 @@ impl Day<Parsed, String, String> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2016, 5)
-}
+advent_lib::solution!(Solver {}, 2016, 5);
@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Result, bail};
 
 type P1Out = u64;
@@ -7,11 +7,14 @@ type Parsed = Vec<String>;
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
+  const PARSE_ONCE: bool = true;
+
   fn parse(
     &self,
     lines: Vec<String>,
     _sample_name: Option<String>,
     _for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     // Observation: every line has at least one [bracket set], many have at least two.
     // None of these sections are close to the end of that line.
@@ -93,6 +96,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2016, 7)
-}
+advent_lib::solution!(Solver {}, 2016, 7);
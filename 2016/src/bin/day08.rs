@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Context, Result, bail};
 
 #[derive(PartialEq, Debug)]
@@ -67,11 +67,14 @@ fn exec(instructions: &Parsed) -> Result<[[bool; GRID_WIDTH]; GRID_HEIGHT]> {
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
+  const PARSE_ONCE: bool = true;
+
   fn parse(
     &self,
     lines: Vec<String>,
     _sample_name: Option<String>,
     _for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     let mut out: Vec<Instruction> = Vec::with_capacity(lines.len());
     for line in lines {
@@ -121,22 +124,18 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
     // Hypothetically, I could actually read the characters printed here.
     // But it's easier to just print the thing.
     Ok(
-      "!\n".to_string() // To force all of the output lines to line up
-        + grid
-          .iter()
-          .map(|row| {
-            row
-              .iter()
-              .map(|b| if *b { '#' } else { '_' })
-              .collect::<String>()
-          })
-          .collect::<Vec<String>>()
-          .join("\n")
-          .as_str(),
+      grid
+        .iter()
+        .map(|row| {
+          row
+            .iter()
+            .map(|b| if *b { '#' } else { '_' })
+            .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n"),
     )
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2016, 8)
-}
+advent_lib::solution!(Solver {}, 2016, 8);
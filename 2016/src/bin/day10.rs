@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Context, Result, bail};
 use fnv::{FnvBuildHasher, FnvHashMap};
 
@@ -79,11 +79,14 @@ fn solve(instructions: &Parsed) -> Bins {
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
+  const PARSE_ONCE: bool = true;
+
   fn parse(
     &self,
     lines: Vec<String>,
     _sample_name: Option<String>,
     _for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     let mut out: Vec<Instruction> = Vec::with_capacity(lines.len());
     for line in lines {
@@ -146,6 +149,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2016, 10)
-}
+advent_lib::solution!(Solver {}, 2016, 10);
@@ -1,6 +1,6 @@
 use std::collections::VecDeque;
 
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Result, bail};
 use fnv::{FnvBuildHasher, FnvHashSet};
 use itertools::Itertools;
@@ -360,11 +360,14 @@ type Parsed = State;
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
+  const PARSE_ONCE: bool = true;
+
   fn parse(
     &self,
     lines: Vec<String>,
     _sample_name: Option<String>,
     _for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     let mut floors: [Floor; 4] = [
       Floor {
@@ -455,6 +458,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2016, 11)
-}
+advent_lib::solution!(Solver {}, 2016, 11);
@@ -1,7 +1,7 @@
 use std::collections::VecDeque;
 
 use advent_lib::grid::{Infinite2dGrid, Infinite2dSet};
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Result, bail};
 
 type P1Out = u64;
@@ -20,11 +20,14 @@ fn wall_test(seed: u64, walls: &mut Infinite2dGrid<bool>, x: i32, y: i32) -> boo
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
+  const PARSE_ONCE: bool = true;
+
   fn parse(
     &self,
     lines: Vec<String>,
     _sample_name: Option<String>,
     _for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     Ok(lines[0].parse().unwrap())
   }
@@ -92,6 +95,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2016, 13)
-}
+advent_lib::solution!(Solver {}, 2016, 13);
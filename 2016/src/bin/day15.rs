@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Context, Result, bail};
 
 #[derive(Debug, Clone)]
@@ -72,11 +72,14 @@ fn solve(discs: &[Disc]) -> Result<i64> {
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
+  const PARSE_ONCE: bool = true;
+
   fn parse(
     &self,
     lines: Vec<String>,
     _sample_name: Option<String>,
     _for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     let mut discs: Vec<Disc> = Vec::with_capacity(lines.len());
     for line in lines {
@@ -119,6 +122,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2016, 15)
-}
+advent_lib::solution!(Solver {}, 2016, 15);
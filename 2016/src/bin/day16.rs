@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::Result;
 
 type P1Out = String;
@@ -69,7 +69,9 @@ fn solve(pattern: &Parsed, target_len: usize) -> Result<String> {
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     Ok(lines[0].chars().map(|c| c != '0').collect())
   }
 
@@ -84,6 +86,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2016, 16)
-}
+advent_lib::solution!(Solver {}, 2016, 16);
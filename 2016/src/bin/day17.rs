@@ -1,6 +1,6 @@
 use std::collections::VecDeque;
 
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Result, bail};
 use crypto::digest::Digest;
 use crypto::md5::Md5;
@@ -98,11 +98,14 @@ fn solve(which: PartId, passkey: &String) -> Result<String> {
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
+  const PARSE_ONCE: bool = true;
+
   fn parse(
     &self,
     lines: Vec<String>,
     _sample_name: Option<String>,
     _for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     Ok(lines[0].to_string())
   }
@@ -116,6 +119,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2016, 17)
-}
+advent_lib::solution!(Solver {}, 2016, 17);
@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Context, Result};
 
 type P1Out = u32;
@@ -7,11 +7,14 @@ type Parsed = Vec<(u32, u32)>;
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
+  const PARSE_ONCE: bool = true;
+
   fn parse(
     &self,
     lines: Vec<String>,
     _sample_name: Option<String>,
     _for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     let mut out: Vec<(u32, u32)> = Vec::with_capacity(lines.len());
     for line in lines {
@@ -59,6 +62,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2016, 20)
-}
+advent_lib::solution!(Solver {}, 2016, 20);
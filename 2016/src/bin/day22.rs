@@ -1,6 +1,6 @@
 use advent_lib::{
   grid::Infinite2dSet,
-  runner::{Day, PartId},
+  runner::{Day, Params, PartId},
 };
 use anyhow::{Context, Result};
 
@@ -18,11 +18,14 @@ type Parsed = Vec<Node>;
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
+  const PARSE_ONCE: bool = true;
+
   fn parse(
     &self,
     lines: Vec<String>,
     _sample_name: Option<String>,
     _for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     let mut out: Vec<Node> = Vec::with_capacity(lines.len() - 2);
     for line in lines[2..].iter() {
@@ -177,6 +180,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2016, 22)
-}
+advent_lib::solution!(Solver {}, 2016, 22);
@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId, skip};
 use anyhow::Result;
 
 type P1Out = u64;
@@ -21,15 +21,17 @@ fn solve(input: u64, secret_a: u64, secret_b: u64) -> u64 {
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
+  const PARSE_ONCE: bool = true;
+
   fn parse(
     &self,
     lines: Vec<String>,
     sample_name: Option<String>,
     _for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     if sample_name.is_some() {
-      // Sample is a different program.
-      return Ok((0, 0));
+      return skip("The sample is a different program");
     }
     let secret_a: u64 = lines[19]
       .split_once(' ')
@@ -61,6 +63,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2016, 23)
-}
+advent_lib::solution!(Solver {}, 2016, 23);
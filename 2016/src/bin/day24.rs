@@ -2,7 +2,7 @@ use std::collections::VecDeque;
 
 use advent_lib::{
   grid::{Infinite2dGrid, Infinite2dSet},
-  runner::{Day, PartId},
+  runner::{Day, Params, PartId},
 };
 use anyhow::{Context, Result};
 use fnv::{FnvBuildHasher, FnvHashMap};
@@ -108,7 +108,9 @@ fn solve(
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     let h = lines.len();
     let w = lines[0].len();
 
@@ -143,6 +145,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2016, 24)
-}
+advent_lib::solution!(Solver {}, 2016, 24);
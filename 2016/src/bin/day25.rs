@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId, skip};
 use anyhow::Result;
 
 type P1Out = u64;
@@ -7,14 +7,17 @@ type Parsed = (u64, u64);
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
+  const PARSE_ONCE: bool = true;
+
   fn parse(
     &self,
     lines: Vec<String>,
     sample_name: Option<String>,
     _for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     if sample_name.is_some() {
-      return Ok((0, 0));
+      return skip("The sample is a different program");
     }
     let secret_1 = lines[1]
       .split_once(' ')
@@ -35,11 +38,7 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
     Ok((secret_1, secret_2))
   }
 
-  fn part1(&self, (secret_1, secret_2): &Parsed, sample_name: Option<String>) -> Result<P1Out> {
-    if sample_name.is_some() {
-      return Ok(0);
-    }
-
+  fn part1(&self, (secret_1, secret_2): &Parsed, _: Option<String>) -> Result<P1Out> {
     // The input gives two secret values, on the second and third line.
     // It multiplies those together, adds the value from register `a`, and
     // prints the bits of this salt (least significant to most), on repeat, forever.
@@ -76,10 +75,8 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
 
   fn part2(&self, _lines: &Parsed, _sample_name: Option<String>) -> Result<P2Out> {
     // On day 25, there is no part 2. This star is granted by earning the other 49.
-    Ok(0)
+    skip("There is no part 2 on day 25")
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2016, 25)
-}
+advent_lib::solution!(Solver {}, 2016, 25);
@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::Result;
 
 type P1Out = u32;
@@ -7,7 +7,9 @@ type Parsed = Vec<Vec<u32>>;
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     let mut out: Vec<Vec<u32>> = Vec::with_capacity(lines.len());
     for line in lines {
       let mut row: Vec<u32> = Vec::new();
@@ -53,6 +55,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2017, 2)
-}
+advent_lib::solution!(Solver {}, 2017, 2);
@@ -1,6 +1,6 @@
 use advent_lib::{
   grid::Infinite2dGrid,
-  runner::{Day, PartId},
+  runner::{Day, Params, PartId},
 };
 use anyhow::{Result, bail};
 
@@ -10,11 +10,14 @@ type Parsed = u32;
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
+  const PARSE_ONCE: bool = true;
+
   fn parse(
     &self,
     lines: Vec<String>,
     _sample_name: Option<String>,
     _for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     Ok(lines[0].parse().unwrap())
   }
@@ -107,6 +110,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2017, 3)
-}
+advent_lib::solution!(Solver {}, 2017, 3);
@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::Result;
 
 type P1Out = usize;
@@ -26,7 +26,9 @@ fn solve(initial: &[i64], part_2: bool) -> usize {
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     Ok(lines.iter().map(|line| line.parse().unwrap()).collect())
   }
 
@@ -39,6 +41,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2017, 5)
-}
+advent_lib::solution!(Solver {}, 2017, 5);
@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::Result;
 use fnv::{FnvBuildHasher, FnvHashMap};
 
@@ -76,7 +76,9 @@ fn solve(initial_blocks: &[u8]) -> (usize, usize) {
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     Ok(
       lines[0]
         .split_whitespace()
@@ -96,6 +98,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2017, 6)
-}
+advent_lib::solution!(Solver {}, 2017, 6);
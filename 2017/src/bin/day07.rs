@@ -1,6 +1,6 @@
 use std::{collections::VecDeque, rc::Rc};
 
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Context, Result, bail};
 use fnv::{FnvBuildHasher, FnvHashMap, FnvHashSet};
 
@@ -17,7 +17,9 @@ type Parsed = Rc<Node>;
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     // Part 1's answer is needed to construct the graph for part 2, so figure it out in the parse.
     let mut all_children: FnvHashSet<&str> =
       FnvHashSet::with_capacity_and_hasher(lines.len() - 1, FnvBuildHasher::default());
@@ -144,6 +146,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2017, 7)
-}
+advent_lib::solution!(Solver {}, 2017, 7);
@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Context, Result};
 use fnv::{FnvBuildHasher, FnvHashMap};
 
@@ -8,7 +8,9 @@ type Parsed = (i32, i32);
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     // This one is simple enough to solve both halves in the parse function.
     let mut registers: FnvHashMap<&str, i32> =
       FnvHashMap::with_capacity_and_hasher(lines.len(), FnvBuildHasher::default());
@@ -60,6 +62,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2017, 8)
-}
+advent_lib::solution!(Solver {}, 2017, 8);
@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::Result;
 
 type P1Out = u64;
@@ -50,6 +50,7 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
     lines: Vec<String>,
     _sample_name: Option<String>,
     for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     match for_part {
       PartId::P1 => Ok(lines[0].split(',').map(|n| n.parse().unwrap()).collect()),
@@ -75,6 +76,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2017, 10)
-}
+advent_lib::solution!(Solver {}, 2017, 10);
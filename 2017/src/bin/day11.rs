@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Result, bail};
 
 #[derive(Debug)]
@@ -113,7 +113,9 @@ fn solve(dirs: &[Dir]) -> (u64, u64) {
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     let mut dirs = vec![];
     for d in lines[0].split(',') {
       dirs.push(match d {
@@ -138,6 +140,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2017, 11)
-}
+advent_lib::solution!(Solver {}, 2017, 11);
@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Result, bail};
 use fnv::{FnvBuildHasher, FnvHashSet};
 
@@ -8,7 +8,9 @@ type Parsed = Vec<FnvHashSet<usize>>;
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     let links: Vec<Vec<usize>> = lines
       .iter()
       .map(|line| {
@@ -66,6 +68,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2017, 12)
-}
+advent_lib::solution!(Solver {}, 2017, 12);
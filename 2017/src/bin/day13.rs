@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Context, Result, bail};
 use itertools::Itertools;
 
@@ -8,7 +8,9 @@ type Parsed = Vec<(i64, i64, i64)>;
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     let mut layers = Vec::with_capacity(lines.len());
     for line in lines {
       let (left, right) = line.split_once(": ").context("")?;
@@ -145,6 +147,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2017, 13)
-}
+advent_lib::solution!(Solver {}, 2017, 13);
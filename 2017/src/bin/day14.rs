@@ -1,6 +1,6 @@
 use advent_lib::{
   grid::Infinite2dSet,
-  runner::{Day, PartId},
+  runner::{Day, Params, PartId},
 };
 use anyhow::Result;
 use rayon::prelude::*;
@@ -65,7 +65,9 @@ fn make_grid(in_key: &[u8]) -> Vec<u128> {
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     let key: Vec<u8> = lines[0].bytes().collect();
     Ok(make_grid(&key))
   }
@@ -115,6 +117,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2017, 14)
-}
+advent_lib::solution!(Solver {}, 2017, 14);
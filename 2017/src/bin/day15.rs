@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Context, Result};
 
 type P1Out = u64;
@@ -27,11 +27,14 @@ fn step(seed: &mut u64, factor: u64) {
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
+  const PARSE_ONCE: bool = true;
+
   fn parse(
     &self,
     lines: Vec<String>,
     _sample_name: Option<String>,
     _for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     let a = lines[0].split_whitespace().last().context("")?.parse()?;
     let b = lines[1].split_whitespace().last().context("")?.parse()?;
@@ -71,6 +74,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2017, 15)
-}
+advent_lib::solution!(Solver {}, 2017, 15);
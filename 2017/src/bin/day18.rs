@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId, skip};
 use anyhow::Result;
 
 type P1Out = u64;
@@ -10,9 +10,17 @@ const DIVISOR: u64 = 0x7FFFFFFF;
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, sample_name: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(
+    &self,
+    lines: Vec<String>,
+    sample_name: Option<String>,
+    _: PartId,
+    _: &Params,
+  ) -> Result<Parsed> {
     if sample_name.is_some() {
-      return Ok([0; 4]);
+      return skip("The sample is a different program");
     }
     // The values we care about are at the end of specific lines.
     // Everything else appears to be the same for everyone's input.
@@ -20,11 +28,7 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
     Ok([read(9), read(10), read(12), read(13)])
   }
 
-  fn part1(&self, secrets: &Parsed, sample_name: Option<String>) -> Result<P1Out> {
-    if sample_name.is_some() {
-      return Ok(1);
-    }
-
+  fn part1(&self, secrets: &Parsed, _: Option<String>) -> Result<P1Out> {
     // The program starts by using this method to generate RNG_POOL random integers, and queue
     // them up those values modulo 10_000.
     // The first thing it calls `rcv` on is the last of those values generated, so all
@@ -40,7 +44,7 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
     Ok(state % 10_000)
   }
 
-  fn part2(&self, secrets: &Parsed, sample_name: Option<String>) -> Result<P2Out> {
+  fn part2(&self, secrets: &Parsed, _: Option<String>) -> Result<P2Out> {
     // The input program is secretly the first version of bubble sort CS students learn:
     // - Generate RNG_POOL pseudo-random numbers in 0..=9999
     // - Loop through its indices, swapping an element with
@@ -52,9 +56,6 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
     //   by the length of the list and divided by two, is the answer
     //   (divide by two because it only wants what program B sent).
 
-    if sample_name.is_some() {
-      return Ok(1);
-    }
     let &[seed, factor_1, factor_2, constant] = secrets;
 
     let mut list: Vec<u64> = Vec::with_capacity(RNG_POOL);
@@ -86,6 +87,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2017, 18)
-}
+advent_lib::solution!(Solver {}, 2017, 18);
@@ -1,6 +1,6 @@
 use advent_lib::{
   direction::{CardinalDirection, Rotation},
-  runner::{Day, PartId},
+  runner::{Day, Params, PartId},
 };
 use anyhow::{Context, Result};
 
@@ -58,7 +58,9 @@ fn trace(maze: &[Vec<char>]) -> Result<(String, u32)> {
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     Ok(lines.iter().map(|line| line.chars().collect()).collect())
   }
 
@@ -73,6 +75,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2017, 19)
-}
+advent_lib::solution!(Solver {}, 2017, 19);
@@ -1,6 +1,6 @@
 use std::rc::Rc;
 
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::Result;
 use fnv::{FnvBuildHasher, FnvHashMap};
 use itertools::Itertools;
@@ -59,7 +59,9 @@ type Parsed = Vec<Particle>;
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     Ok(
       lines
         .iter()
@@ -164,6 +166,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2017, 20)
-}
+advent_lib::solution!(Solver {}, 2017, 20);
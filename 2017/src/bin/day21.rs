@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Context, Result};
 use fnv::{FnvBuildHasher, FnvHashMap};
 
@@ -311,11 +311,14 @@ fn solve(rules: &Parsed, iterations: usize) -> Result<usize> {
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
+  const PARSE_ONCE: bool = true;
+
   fn parse(
     &self,
     lines: Vec<String>,
     _sample_name: Option<String>,
     _for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     let mut rules: FnvHashMap<Cell, Vec<Cell>> =
       FnvHashMap::with_capacity_and_hasher(lines.len() * 4, FnvBuildHasher::default());
@@ -409,6 +412,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2017, 21)
-}
+advent_lib::solution!(Solver {}, 2017, 21);
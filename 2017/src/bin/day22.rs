@@ -1,7 +1,7 @@
 use advent_lib::{
   direction::{CardinalDirection, Rotation},
   grid::Infinite2dGrid,
-  runner::{Day, PartId},
+  runner::{Day, Params, PartId},
 };
 use anyhow::Result;
 
@@ -19,7 +19,9 @@ type Parsed = (usize, Infinite2dGrid<Cell>);
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     let dim = lines.len();
     let mut grid: Infinite2dGrid<Cell> = Infinite2dGrid::new(dim * dim);
     for (y, line) in lines.iter().enumerate() {
@@ -94,6 +96,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2017, 22)
-}
+advent_lib::solution!(Solver {}, 2017, 22);
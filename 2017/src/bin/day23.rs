@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::Result;
 
 type P1Out = u64;
@@ -15,6 +15,7 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
     lines: Vec<String>,
     _sample_name: Option<String>,
     for_part: PartId,
+    _: &Params,
   ) -> Result<Parsed> {
     let p = |line: &str| {
       line
@@ -67,6 +68,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   // Slightly more efficient prime number test...
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2017, 23)
-}
+advent_lib::solution!(Solver {}, 2017, 23);
@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Context, Result};
 
 type P1Out = u16;
@@ -73,7 +73,9 @@ fn solve(planks: &[(u16, u16)]) -> (u16, u16) {
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     let mut out: Vec<(u16, u16)> = Vec::with_capacity(lines.len());
     for line in lines {
       let (l, r) = line.split_once('/').context("Unsplittable line?")?;
@@ -91,6 +93,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2017, 24)
-}
+advent_lib::solution!(Solver {}, 2017, 24);
@@ -1,6 +1,6 @@
 use advent_lib::{
   direction::Rotation,
-  runner::{Day, PartId},
+  runner::{Day, Params, PartId, skip},
 };
 use anyhow::Result;
 use fnv::{FnvBuildHasher, FnvHashMap, FnvHashSet};
@@ -20,7 +20,9 @@ type Parsed = (char, u64, FnvHashMap<char, (Instruction, Instruction)>);
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     let initial_state = lines[0].chars().nth(15).unwrap();
     let iterations: u64 = lines[1].split_whitespace().nth(5).unwrap().parse()?;
     let mut map: FnvHashMap<char, (Instruction, Instruction)> =
@@ -91,10 +93,8 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
 
   fn part2(&self, _: &Parsed, _: Option<String>) -> Result<P2Out> {
     // On day 25, there is no part 2. This star is granted by earning the other 49.
-    Ok(0)
+    skip("There is no part 2 on day 25")
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2017, 25)
-}
+advent_lib::solution!(Solver {}, 2017, 25);
@@ -1,6 +1,6 @@
 use std::cmp::Ordering;
 
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Result, bail};
 use fnv::{FnvBuildHasher, FnvHashMap};
 
@@ -128,7 +128,9 @@ type Parsed = Vec<Log>;
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     let mut out: Vec<Log> = Vec::with_capacity(lines.len());
     for line in lines {
       let month: u8 = line[6..=7].parse()?;
@@ -197,6 +199,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2018, 4)
-}
+advent_lib::solution!(Solver {}, 2018, 4);
@@ -1,6 +1,6 @@
 use advent_lib::{
   grid::Infinite2dSet,
-  runner::{Day, PartId},
+  runner::{Day, Params, PartId},
 };
 use anyhow::{Context, Result};
 use rayon::prelude::*;
@@ -22,7 +22,9 @@ struct Parsed {
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     let (mut min_x, mut max_x, mut min_y, mut max_y) = (i32::MAX, i32::MIN, i32::MAX, i32::MIN);
     let mut points: Vec<Point> = Vec::with_capacity(lines.len());
     for line in lines {
@@ -155,6 +157,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2018, 6)
-}
+advent_lib::solution!(Solver {}, 2018, 6);
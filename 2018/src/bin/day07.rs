@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Result, bail};
 use fnv::{FnvBuildHasher, FnvHashMap};
 use itertools::Itertools;
@@ -9,7 +9,9 @@ type Parsed = FnvHashMap<char, Vec<char>>;
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     let mut map: Parsed =
       FnvHashMap::with_capacity_and_hasher(lines.len(), FnvBuildHasher::default());
     for line in lines {
@@ -121,6 +123,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2018, 7)
-}
+advent_lib::solution!(Solver {}, 2018, 7);
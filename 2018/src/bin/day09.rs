@@ -1,6 +1,6 @@
 use std::collections::VecDeque;
 
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Context, Result};
 
 type P1Out = usize;
@@ -33,7 +33,9 @@ fn solve(num_players: usize, num_marbles: usize) -> Result<usize> {
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     let mut w = lines[0].split_whitespace();
     let num_players: usize = w.next().context("How many players?")?.parse()?;
     let last_marble_score: usize = w
@@ -52,6 +54,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2018, 9)
-}
+advent_lib::solution!(Solver {}, 2018, 9);
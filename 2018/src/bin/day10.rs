@@ -1,5 +1,10 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::{
+  grid::Infinite2dSet,
+  ocr,
+  runner::{Day, Params, PartId, skip},
+};
 use anyhow::{Result, bail};
+use std::time::Duration;
 
 type P1Out = String;
 type P2Out = usize;
@@ -7,7 +12,11 @@ type Parsed = Vec<(i32, i32, i32, i32)>;
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+  // Points that never start spreading out, like ones that stand still, run the full 100,000 steps before giving up.
+  const TIME_LIMIT: Duration = Duration::from_secs(5);
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     let mut out = Vec::with_capacity(lines.len());
     for line in lines {
       let (raw_px, r0) = line[10..].split_once(',').unwrap();
@@ -26,74 +35,47 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
 
   fn part1(&self, in_points: &Parsed, sample_name: Option<String>) -> Result<P1Out> {
     if sample_name.is_some() {
-      // The latters are a different height for the sample,
-      // I don't want to write extra code to deal with that.
-      return Ok("HI".to_string());
+      // The sample's letters are 8 pixels tall, which isn't one of the fonts `ocr` knows.
+      return skip("Can't read the sample's letters");
     }
+    let (_, points) = find_message(in_points)?;
+    let lit: Infinite2dSet = points.iter().map(|&(x, y, _, _)| (x, y)).collect();
+    ocr::read_set(&lit)
+  }
 
-    let letter_height = if sample_name.is_some() { 8 } else { 10 };
-    let mut points = in_points.clone();
-    for _ in 0..100_000 {
-      let min_y = points.iter().map(|p| p.1).min().unwrap();
-      let max_y = points.iter().map(|p| p.1).max().unwrap();
-
-      // Sample uses 8-character-high letters, real data uses 10.
-      // In both cases, when the difference in lowest-to-highest coordinate is that,
-      // we have the pattern.
-      if min_y.abs_diff(max_y) + 1 == letter_height {
-        let min_x = points.iter().map(|p| p.0).min().unwrap();
-        let max_x = points.iter().map(|p| p.0).max().unwrap();
+  fn part2(&self, in_points: &Parsed, _: Option<String>) -> Result<P2Out> {
+    Ok(find_message(in_points)?.0)
+  }
+}
 
-        // Print the grid.
-        // Were it not a ton of effort, I would also transform this into actual
-        // letters based on heuristics, but there's not much point in doing so.
-        println!();
-        for y in min_y..=max_y {
-          for x in min_x..=max_x {
-            if points.iter().any(|(x2, y2, _, _)| *x2 == x && *y2 == y) {
-              print!("#");
-            } else {
-              print!(" ");
-            }
-          }
-          println!();
-        }
-        println!();
+fn height(points: &Parsed) -> u32 {
+  let min_y = points.iter().map(|p| p.1).min().unwrap();
+  let max_y = points.iter().map(|p| p.1).max().unwrap();
+  min_y.abs_diff(max_y)
+}
 
-        return Ok("SEE ABOVE".to_string());
-      }
-      // Step.
-      for (px, py, vx, vy) in points.iter_mut() {
-        *px += *vx;
-        *py += *vy;
-      }
-    }
-    bail!("Failed to find after 100_000 steps");
+fn step(points: &mut Parsed, direction: i32) {
+  for (px, py, vx, vy) in points.iter_mut() {
+    *px += *vx * direction;
+    *py += *vy * direction;
   }
+}
 
-  fn part2(&self, in_points: &Parsed, sample_name: Option<String>) -> Result<P2Out> {
-    let letter_height = if sample_name.is_some() { 8 } else { 10 };
-    let mut points = in_points.clone();
-    for step in 0..100_000 {
-      let min_y = points.iter().map(|p| p.1).min().unwrap();
-      let max_y = points.iter().map(|p| p.1).max().unwrap();
-
-      // Sample uses 8-character-high letters, real data uses 10.
-      // In both cases, when the difference in lowest-to-highest coordinate is that,
-      // we have the pattern.
-      if min_y.abs_diff(max_y) + 1 == letter_height {
-        return Ok(step);
-      }
-      // Step.
-      for (_px, py, _vx, vy) in points.iter_mut() {
-        // *px += *vx; // Not needed for this part
-        *py += *vy;
-      }
+// The message appears when the points are closest together, right before they start spreading out again.
+// That works for the sample's 8-pixel letters and the real input's 10-pixel ones alike.
+fn find_message(in_points: &Parsed) -> Result<(usize, Parsed)> {
+  let mut points = in_points.clone();
+  let mut last_height = height(&points);
+  for seconds in 0..100_000 {
+    step(&mut points, 1);
+    let next_height = height(&points);
+    if next_height > last_height {
+      step(&mut points, -1);
+      return Ok((seconds, points));
     }
-    bail!("Failed to find after 100_000 steps");
+    last_height = next_height;
   }
+  bail!("Failed to find after 100_000 steps");
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2018, 10)
-}
+advent_lib::solution!(Solver {}, 2018, 10);
@@ -1,6 +1,6 @@
 use std::collections::VecDeque;
 
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::Result;
 use fnv::FnvHashSet;
 
@@ -10,7 +10,9 @@ type Parsed = (Vec<bool>, Vec<([bool; 5], bool)>);
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     let initial_state: Vec<bool> = lines[0]
       .split_once(": ")
       .unwrap()
@@ -139,6 +141,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2018, 12)
-}
+advent_lib::solution!(Solver {}, 2018, 12);
@@ -1,7 +1,7 @@
 use advent_lib::{
   direction::{CardinalDirection, Rotation},
   grid::Infinite2dGrid,
-  runner::{Day, PartId},
+  runner::{Day, Params, PartId},
 };
 use anyhow::{Result, bail};
 use fnv::FnvHashSet;
@@ -143,7 +143,9 @@ fn solve(
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     let mut carts: Vec<Cart> = vec![];
     let mut grid = Infinite2dGrid::<RailKind>::new(lines.len() * lines.len());
     for (yy, line) in lines.iter().enumerate() {
@@ -235,6 +237,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2018, 13)
-}
+advent_lib::solution!(Solver {}, 2018, 13);
@@ -1,8 +1,9 @@
 use std::collections::VecDeque;
+use std::time::Duration;
 
 use advent_lib::{
   grid::Infinite2dSet,
-  runner::{Day, PartId},
+  runner::{Day, Params, PartId},
 };
 use anyhow::{Result, bail};
 use fnv::FnvHashMap;
@@ -188,7 +189,11 @@ type Parsed = (usize, usize, Infinite2dSet, Vec<Unit>);
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+  // Part 2 refights the whole battle for each attack power, and a battle has no cap on its rounds.
+  const TIME_LIMIT: Duration = Duration::from_secs(30);
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     let h = lines.len();
     let w = lines[0].len();
     let mut walls = Infinite2dSet::new(w * h / 2);
@@ -259,6 +264,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2018, 15)
-}
+advent_lib::solution!(Solver {}, 2018, 15);
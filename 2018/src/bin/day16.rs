@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::Result;
 
 #[derive(Default, Debug)]
@@ -167,7 +167,9 @@ type Parsed = (Vec<Sample>, Vec<Instruction>);
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     let mut samples: Vec<Sample> = vec![];
     let mut instructions: Vec<Instruction> = vec![];
 
@@ -304,6 +306,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2018, 16)
-}
+advent_lib::solution!(Solver {}, 2018, 16);
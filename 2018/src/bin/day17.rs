@@ -1,6 +1,6 @@
 use advent_lib::{
   grid::Infinite2dSet,
-  runner::{Day, PartId},
+  runner::{Day, Params, PartId},
 };
 use anyhow::Result;
 
@@ -95,7 +95,9 @@ fn solve(&(_x_min, _y_min, y_min, y_max, ref walls): &Parsed) -> Result<(usize,
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     let mut x_min = 500;
     let mut x_max = 500;
     let mut y_min = i32::MAX;
@@ -156,6 +158,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2018, 17)
-}
+advent_lib::solution!(Solver {}, 2018, 17);
@@ -1,6 +1,6 @@
 use advent_lib::{
   grid::Infinite2dGrid,
-  runner::{Day, PartId},
+  runner::{Day, Params, PartId},
 };
 use anyhow::{Result, bail};
 use fnv::{FnvBuildHasher, FnvHashMap};
@@ -150,7 +150,9 @@ fn solve(in_grid: &Infinite2dGrid<Cell>, rounds: usize) -> Result<usize> {
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     let mut grid: Infinite2dGrid<Cell> = Infinite2dGrid::new(lines.len() * lines[0].len());
     for (y, line) in lines.iter().enumerate() {
       let yy = y as i32;
@@ -184,6 +186,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2018, 18)
-}
+advent_lib::solution!(Solver {}, 2018, 18);
@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::Result;
 
 type P1Out = usize;
@@ -42,7 +42,9 @@ fn solve(in_target: usize) -> Result<usize> {
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, _: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, _: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     // It's another "analyze the assembly program" puzzle.
 
     // The input is a mess of initialization logic.
@@ -63,6 +65,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2018, 19)
-}
+advent_lib::solution!(Solver {}, 2018, 19);
@@ -2,7 +2,7 @@ use std::collections::VecDeque;
 
 use advent_lib::{
   grid::{Infinite2dGrid, Infinite2dSet},
-  runner::{Day, PartId},
+  runner::{Day, Params, PartId},
 };
 use anyhow::{Result, bail};
 
@@ -127,7 +127,9 @@ fn build_paths(map: &Infinite2dGrid<Room>) -> Result<(usize, usize)> {
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     Ok(lines[0].to_string())
   }
 
@@ -144,6 +146,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2018, 20)
-}
+advent_lib::solution!(Solver {}, 2018, 20);
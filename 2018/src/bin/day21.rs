@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::Result;
 use fnv::{FnvBuildHasher, FnvHashSet};
 
@@ -38,7 +38,9 @@ fn solve(secret: u64, factor: u64, first_result: bool) -> u64 {
 
 struct Solver {}
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     // The constants on lines 2 and 3 don't impact the output at all
     // All but two constants are either 2^n or (2^n)-1, so they're not secrets.
     // Those last two are probably the only meaningful difference between inputs?
@@ -56,6 +58,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2018, 21)
-}
+advent_lib::solution!(Solver {}, 2018, 21);
@@ -1,6 +1,6 @@
 use advent_lib::{
   grid::Infinite2dGrid,
-  runner::{Day, PartId},
+  runner::{Day, Params, PartId},
 };
 use anyhow::{Result, bail};
 use fnv::{FnvBuildHasher, FnvHashMap, FnvHashSet};
@@ -200,7 +200,15 @@ type P2Out = usize;
 
 struct Solver {}
 impl Day<ClimbingMap, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<ClimbingMap> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(
+    &self,
+    lines: Vec<String>,
+    _: Option<String>,
+    _: PartId,
+    _: &Params,
+  ) -> Result<ClimbingMap> {
     ClimbingMap::parse(&lines)
   }
 
@@ -264,6 +272,4 @@ impl Day<ClimbingMap, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2018, 22)
-}
+advent_lib::solution!(Solver {}, 2018, 22);
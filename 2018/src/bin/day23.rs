@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Context, Result};
 
 type P1Out = usize;
@@ -7,7 +7,9 @@ type Parsed = Vec<(i64, i64, i64, i64)>;
 
 struct Solver;
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     let mut out = Vec::with_capacity(lines.len());
     for line in lines {
       let (left, r) = line[5..].split_once(">, r=").context("Bad line")?;
@@ -100,6 +102,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2018, 23)
-}
+advent_lib::solution!(Solver {}, 2018, 23);
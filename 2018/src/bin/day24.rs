@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Result, bail};
 
 #[derive(Clone, Copy, PartialEq)]
@@ -171,7 +171,9 @@ fn solve(in_groups: &[Group], boost: usize) -> Result<(Team, usize)> {
 
 struct Solver;
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     let mut groups: Vec<Group> = Vec::with_capacity(lines.len() - 3);
     let mut team = Team::ImmuneSystem;
     let mut id = 0;
@@ -258,6 +260,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2018, 24)
-}
+advent_lib::solution!(Solver {}, 2018, 24);
@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId, skip};
 use anyhow::Result;
 
 #[derive(Clone, PartialEq, Debug)]
@@ -26,7 +26,9 @@ type Parsed = Vec<Point>;
 
 struct Solver;
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     let mut points: Vec<Point> = Vec::with_capacity(lines.len());
     for line in lines {
       let mut parts = line.split(',');
@@ -69,10 +71,9 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 
   fn part2(&self, _lines: &Parsed, _sample_name: Option<String>) -> Result<P2Out> {
-    Ok(0)
+    // On day 25, there is no part 2. This star is granted by earning the other 49.
+    skip("There is no part 2 on day 25")
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2018, 25)
-}
+advent_lib::solution!(Solver {}, 2018, 25);
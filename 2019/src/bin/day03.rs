@@ -1,6 +1,6 @@
 use advent_lib::{
   direction::CardinalDirection,
-  runner::{Day, PartId},
+  runner::{Day, Params, PartId},
 };
 use anyhow::{Result, bail};
 
@@ -84,7 +84,9 @@ fn find_intersections(a_lines: &[Line], b_lines: &[Line]) -> Vec<(i32, i32, i32)
 
 struct Solver;
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     let parse_line = |line: &str| -> Result<Vec<Move>> {
       let mut list: Vec<Move> = vec![];
       for part in line.split(',') {
@@ -133,6 +135,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2019, 3)
-}
+advent_lib::solution!(Solver {}, 2019, 3);
@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::Result;
 
 type P1Out = usize;
@@ -72,7 +72,9 @@ fn is_valid(digits: &[u8; 6], part2: bool) -> bool {
 
 struct Solver;
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     let (lo, hi) = lines[0].split_once('-').unwrap();
     Ok((lo.parse()?, hi.parse()?))
   }
@@ -102,6 +104,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2019, 4)
-}
+advent_lib::solution!(Solver {}, 2019, 4);
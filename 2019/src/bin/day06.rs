@@ -1,6 +1,6 @@
 use std::collections::VecDeque;
 
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Result, bail};
 use fnv::{FnvBuildHasher, FnvHashMap, FnvHashSet};
 
@@ -12,7 +12,9 @@ type Parsed = FnvHashMap<String, Vec<String>>;
 
 struct Solver;
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     let mut out: FnvHashMap<String, Vec<String>> =
       FnvHashMap::with_capacity_and_hasher(lines.len(), FnvBuildHasher::default());
     for line in lines {
@@ -87,6 +89,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2019, 6)
-}
+advent_lib::solution!(Solver {}, 2019, 6);
@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use advent_of_code_2019::intcode::{Execution, execute};
 use anyhow::Result;
 use itertools::Itertools;
@@ -9,7 +9,9 @@ type Parsed = Vec<i64>;
 
 struct Solver;
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     Ok(lines[0].split(',').map(|n| n.parse().unwrap()).collect())
   }
 
@@ -53,6 +55,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2019, 7)
-}
+advent_lib::solution!(Solver {}, 2019, 7);
@@ -1,13 +1,18 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::{
+  ocr,
+  runner::{Day, Params, PartId},
+};
 use anyhow::{Result, bail};
 
 type P1Out = u64;
-type P2Out = u64;
+type P2Out = String;
 type Parsed = String;
 
 struct Solver;
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     Ok(lines[0].to_string())
   }
 
@@ -77,23 +82,12 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
       }
       x += 1;
     }
-    for row in image {
-      for ch in row {
-        print!(
-          "{}",
-          match ch {
-            '0' => ' ',
-            '1' => '#',
-            _ => '!',
-          }
-        );
-      }
-      println!();
-    }
-    Ok(0)
+    let pixels: Vec<Vec<bool>> = image
+      .iter()
+      .map(|row| row.iter().map(|&ch| ch == '1').collect())
+      .collect();
+    ocr::read_pixels(&pixels)
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2019, 8)
-}
+advent_lib::solution!(Solver {}, 2019, 8);
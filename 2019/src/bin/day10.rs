@@ -1,5 +1,5 @@
 use advent_lib::grid::Infinite2dSet;
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Context, Result, bail};
 use std::f64::consts::PI;
 
@@ -81,7 +81,9 @@ fn find_best_position(asteroids: &Infinite2dSet) -> Result<(i32, i32, usize)> {
 
 struct Solver;
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     Ok(Infinite2dSet::from_input_lines(&lines, |c| c == '#'))
   }
 
@@ -152,6 +154,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2019, 10)
-}
+advent_lib::solution!(Solver {}, 2019, 10);
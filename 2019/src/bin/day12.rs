@@ -1,6 +1,6 @@
 use std::cmp::Ordering;
 
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Result, bail};
 
 #[derive(Clone, PartialEq, Eq, Debug, Hash)]
@@ -140,7 +140,9 @@ fn lcm(a: usize, b: usize) -> usize {
 
 struct Solver;
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     Ok(
       lines
         .iter()
@@ -192,6 +194,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2019, 12)
-}
+advent_lib::solution!(Solver {}, 2019, 12);
@@ -2,7 +2,7 @@ use std::cmp::Ordering;
 
 use advent_lib::{
   grid::Infinite2dGrid,
-  runner::{Day, PartId},
+  runner::{Day, Params, PartId},
 };
 use advent_of_code_2019::intcode::execute;
 use anyhow::{Result, bail};
@@ -81,7 +81,9 @@ fn update_state(outputs: &[i64], grid: &mut Infinite2dGrid<Tile>) -> Result<Stat
 
 struct Solver;
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     Ok(lines[0].split(',').map(|n| n.parse().unwrap()).collect())
   }
 
@@ -123,6 +125,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2019, 13)
-}
+advent_lib::solution!(Solver {}, 2019, 13);
@@ -1,6 +1,6 @@
 // use std::collections::VecDeque;
 
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::Result;
 use fnv::{FnvBuildHasher, FnvHashMap, FnvHashSet};
 
@@ -55,7 +55,15 @@ fn ore_per_fuel(needed_fuel: usize, reactions: &[Reaction]) -> usize {
 
 struct Solver;
 impl Day<Vec<Reaction>, usize, usize> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Vec<Reaction>> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(
+    &self,
+    lines: Vec<String>,
+    _: Option<String>,
+    _: PartId,
+    _: &Params,
+  ) -> Result<Vec<Reaction>> {
     let mut out = vec![];
     for line in lines {
       let (l0, out_raw) = line.split_once(" => ").unwrap();
@@ -103,6 +111,4 @@ impl Day<Vec<Reaction>, usize, usize> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2019, 14)
-}
+advent_lib::solution!(Solver {}, 2019, 14);
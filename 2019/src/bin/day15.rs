@@ -2,7 +2,7 @@ use std::collections::VecDeque;
 
 use advent_lib::{
   grid::Infinite2dGrid,
-  runner::{Day, PartId},
+  runner::{Day, Params, PartId},
 };
 use advent_of_code_2019::intcode::execute;
 use anyhow::{Context, Result, bail};
@@ -134,7 +134,9 @@ fn discover_maze(initial_tape: &[i64]) -> Result<Infinite2dGrid<Tile>> {
 
 struct Solver;
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     let instructions: Vec<i64> = lines[0].split(',').map(|n| n.parse().unwrap()).collect();
     discover_maze(&instructions)
   }
@@ -196,6 +198,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2019, 15)
-}
+advent_lib::solution!(Solver {}, 2019, 15);
@@ -3,7 +3,7 @@ use std::collections::VecDeque;
 use advent_lib::{
   direction::{CardinalDirection, Rotation},
   grid::Infinite2dSet,
-  runner::{Day, PartId},
+  runner::{Day, Params, PartId},
 };
 use advent_of_code_2019::intcode::execute;
 use anyhow::{Result, bail};
@@ -303,7 +303,9 @@ fn to_inputs(from: &Vec<&str>) -> Vec<i64> {
 
 struct Solver;
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     Ok(lines[0].split(',').map(|n| n.parse().unwrap()).collect())
   }
 
@@ -370,6 +372,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2019, 17)
-}
+advent_lib::solution!(Solver {}, 2019, 17);
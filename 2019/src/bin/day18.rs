@@ -5,7 +5,7 @@ use std::{
 
 use advent_lib::{
   grid::{Infinite2dGrid, Infinite2dSet},
-  runner::{Day, PartId},
+  runner::{Day, Params, PartId},
 };
 use anyhow::{Result, bail};
 use fnv::{FnvBuildHasher, FnvHashSet};
@@ -87,7 +87,9 @@ fn solve_area(input: &Parsed) -> Result<usize> {
 
 struct Solver;
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     let mut walls = Infinite2dSet::new(lines.len() * lines[0].len() / 2);
     let mut start = (0, 0);
     let mut keys = Infinite2dGrid::new(26);
@@ -188,6 +190,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2019, 18)
-}
+advent_lib::solution!(Solver {}, 2019, 18);
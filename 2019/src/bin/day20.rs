@@ -2,7 +2,7 @@ use std::collections::{BinaryHeap, VecDeque};
 
 use advent_lib::{
   grid::{Infinite2dGrid, Infinite2dSet},
-  runner::{Day, PartId},
+  runner::{Day, Params, PartId},
 };
 use anyhow::{Result, bail};
 use fnv::{FnvBuildHasher, FnvHashMap};
@@ -50,7 +50,9 @@ impl PartialOrd for State {
 
 struct Solver;
 impl Day<Maze, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Maze> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Maze> {
     // Facts to consider:
     // - Each label marks the only dot it touches as a key location
     // - AA is the entrance, ZZ is the exit
@@ -276,6 +278,4 @@ impl Day<Maze, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2019, 20)
-}
+advent_lib::solution!(Solver {}, 2019, 20);
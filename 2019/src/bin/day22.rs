@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Result, bail};
 
 type P1Out = u128;
@@ -11,8 +11,8 @@ enum Action {
   Cut(i32),
   Inc(i32),
 }
-// Size of deck, list of techniques
-type Parsed = (u128, Vec<Action>);
+// Size of deck, the card part 1 follows, list of techniques
+type Parsed = (u128, u128, Vec<Action>);
 
 // Computes (a*x+b)**m.div_euclid(n), but with the pow2 exponentiation trick so it all fits
 fn modular_exponentiate(a: i128, b: i128, m: i128, n: i128) -> (i128, i128) {
@@ -51,15 +51,17 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   fn parse(
     &self,
     lines: Vec<String>,
-    sample_name: Option<String>,
+    _: Option<String>,
     part_id: PartId,
+    params: &Params,
   ) -> Result<Parsed> {
+    // The sample shuffles a deck of 10 for both parts.
     Ok((
-      match (sample_name, part_id) {
-        (Some(_), _) => 10,
-        (None, PartId::P1) => 10_007,
-        (None, PartId::P2) => 119_315_717_514_047,
+      match part_id {
+        PartId::P1 => params.get_or::<u64>("deck_size", 10_007)? as u128,
+        PartId::P2 => params.get_or::<u64>("big_deck_size", 119_315_717_514_047)? as u128,
       },
+      params.get_or::<u64>("card", 2019)? as u128,
       lines
         .iter()
         .map(|line| {
@@ -83,9 +85,9 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
     ))
   }
 
-  fn part1(&self, (deck_size, actions): &Parsed, sample_name: Option<String>) -> Result<P1Out> {
+  fn part1(&self, (deck_size, card, actions): &Parsed, _: Option<String>) -> Result<P1Out> {
     // We only need to track one card's position, so we don't need to generate (let alone shuffle) the deck.
-    let mut pos = if sample_name.is_some() { 7 } else { 2019 };
+    let mut pos = *card;
     for action in actions.iter() {
       match action {
         Action::Error(cause) => bail!("{cause}"),
@@ -116,7 +118,7 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
     Ok(pos)
   }
 
-  fn part2(&self, (deck_size, actions): &Parsed, _: Option<String>) -> Result<P2Out> {
+  fn part2(&self, (deck_size, _, actions): &Parsed, _: Option<String>) -> Result<P2Out> {
     // We now track a position rather than a card, and do the full shuffle chain ~101 trillion times.
     // We do this on a deck with ~119 trillion cards, as well.
     // Part 1 runs in about 600ns, but with that many iterations it'd still run in O(heat_death) time.
@@ -152,6 +154,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2019, 22)
-}
+advent_lib::solution!(Solver {}, 2019, 22);
@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::Result;
 use fnv::{FnvBuildHasher, FnvHashMap};
 
@@ -204,7 +204,9 @@ impl std::fmt::Display for State {
 
 struct Solver;
 impl Day<State, P1Out, P2Out> for Solver {
-  fn parse(&self, raw: Vec<String>, _: Option<String>, _: PartId) -> Result<State> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, raw: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<State> {
     Ok(State::from(raw.join("").as_str()))
   }
 
@@ -270,6 +272,4 @@ impl Day<State, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2019, 24)
-}
+advent_lib::solution!(Solver {}, 2019, 24);
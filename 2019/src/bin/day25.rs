@@ -1,7 +1,7 @@
 use advent_lib::{
   color::{RED, RESET, YELLOW},
   direction::CardinalDirection,
-  runner::{Day, PartId},
+  runner::{Day, Params, PartId, skip},
 };
 use advent_of_code_2019::intcode::{Execution, execute};
 use anyhow::Result;
@@ -58,7 +58,9 @@ fn print_output(result: &Execution) {
 
 struct Solver;
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     Ok(lines[0].split(',').map(|n| n.parse().unwrap()).collect())
   }
 
@@ -257,10 +259,9 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 
   fn part2(&self, _lines: &Parsed, _sample_name: Option<String>) -> Result<P2Out> {
-    Ok(0)
+    // On day 25, there is no part 2. This star is granted by earning the other 49.
+    skip("There is no part 2 on day 25")
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2019, 25)
-}
+advent_lib::solution!(Solver {}, 2019, 25);
@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::Result;
 
 type P1Out = usize;
@@ -35,7 +35,9 @@ type Parsed = Vec<PolicyAndPassword>;
 
 struct Solver;
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     // This works great and is concise, but it's way more performant to skip the regex entirely.
     // let parse_re = Regex::new(r"(?<lo>\d+)-(?<hi>\d+) (?<ch>[a-z]): (?<password>.+)")?;
     Ok(
@@ -72,6 +74,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2020, 2)
-}
+advent_lib::solution!(Solver {}, 2020, 2);
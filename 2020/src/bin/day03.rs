@@ -1,6 +1,6 @@
 use advent_lib::{
   grid::Infinite2dSet,
-  runner::{Day, PartId},
+  runner::{Day, Params, PartId},
 };
 use anyhow::Result;
 
@@ -29,7 +29,9 @@ impl Parsed {
 
 struct Solver;
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     // Typecasting these now avoids doing it on every iteration in tree_check.
     let width = lines[0].len() as i32;
     let height = lines.len() as i32;
@@ -56,6 +58,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2020, 3)
-}
+advent_lib::solution!(Solver {}, 2020, 3);
@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Result, bail};
 
 type P1Out = u64;
@@ -35,7 +35,9 @@ fn find_invalid_index(list: &Vec<u64>) -> Result<usize> {
 
 struct Solver;
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     let items = lines.iter().map(|n| n.parse().unwrap()).collect();
     let first_invalid_index = find_invalid_index(&items)?;
     Ok((items, first_invalid_index))
@@ -74,6 +76,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2020, 9)
-}
+advent_lib::solution!(Solver {}, 2020, 9);
@@ -1,6 +1,6 @@
 use advent_lib::{
   grid::Infinite2dSet,
-  runner::{Day, PartId},
+  runner::{Day, Params, PartId},
 };
 use anyhow::Result;
 
@@ -22,7 +22,9 @@ fn is_accessible(grid: &Infinite2dSet, x: i32, y: i32) -> bool {
 
 struct Solver;
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     Ok(Infinite2dSet::from_input_lines(&lines, |c| c == '@'))
   }
 
@@ -61,6 +63,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2025, 4)
-}
+advent_lib::solution!(Solver {}, 2025, 4);
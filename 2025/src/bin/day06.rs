@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::Result;
 
 type P1Out = usize;
@@ -24,7 +24,13 @@ fn solve(columns: &Parsed) -> usize {
 
 struct Solver;
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, for_part: PartId) -> Result<Parsed> {
+  fn parse(
+    &self,
+    lines: Vec<String>,
+    _: Option<String>,
+    for_part: PartId,
+    _: &Params,
+  ) -> Result<Parsed> {
     // Parsing: read the last line to get the operators and number of columns, then parse the columns themselves
     let mut columns: Vec<(Op, Vec<usize>, usize, usize)> = vec![];
 
@@ -95,6 +101,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2025, 6)
-}
+advent_lib::solution!(Solver {}, 2025, 6);
@@ -1,6 +1,6 @@
 use fnv::{FnvBuildHasher, FnvHashSet};
 
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::Result;
 
 #[derive(Clone, Eq, Hash, PartialEq)]
@@ -93,7 +93,9 @@ fn solve(points: &[Point], stop_after: usize) -> (Vec<FnvHashSet<&Point>>, &Poin
 
 struct Solver;
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     let mut points: Vec<Point> = Vec::with_capacity(lines.len());
     for line in lines {
       let mut parts = line.split(',');
@@ -123,6 +125,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2025, 8)
-}
+advent_lib::solution!(Solver {}, 2025, 8);
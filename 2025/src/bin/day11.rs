@@ -1,6 +1,6 @@
 use std::collections::VecDeque;
 
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::{Context, Result};
 use fnv::{FnvBuildHasher, FnvHashMap, FnvHashSet};
 
@@ -63,7 +63,9 @@ fn memoized_dfs(
 
 struct Solver;
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId) -> Result<Parsed> {
+  const PARSE_ONCE: bool = true;
+
+  fn parse(&self, lines: Vec<String>, _: Option<String>, _: PartId, _: &Params) -> Result<Parsed> {
     let mut map: FnvHashMap<String, FnvHashSet<String>> =
       FnvHashMap::with_capacity_and_hasher(lines.len(), FnvBuildHasher::default());
     for line in lines {
@@ -91,6 +93,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2025, 11)
-}
+advent_lib::solution!(Solver {}, 2025, 11);
@@ -1,4 +1,4 @@
-use advent_lib::runner::{Day, PartId};
+use advent_lib::runner::{Day, Params, PartId};
 use anyhow::Result;
 
 type P1Out = u64;
@@ -13,7 +13,13 @@ struct Parsed {
 
 struct Solver;
 impl Day<Parsed, P1Out, P2Out> for Solver {
-  fn parse(&self, lines: Vec<String>, _: Option<String>, for_part: PartId) -> Result<Parsed> {
+  fn parse(
+    &self,
+    lines: Vec<String>,
+    _: Option<String>,
+    for_part: PartId,
+    _: &Params,
+  ) -> Result<Parsed> {
     let mut shapes: Vec<[[bool; 3]; 3]> = vec![];
     let mut regions: Vec<(usize, usize, Vec<usize>)> = vec![];
 
@@ -85,6 +91,4 @@ impl Day<Parsed, P1Out, P2Out> for Solver {
   }
 }
 
-fn main() -> Result<()> {
-  Solver {}.run(2025, 12)
-}
+advent_lib::solution!(Solver {}, 2025, 12);
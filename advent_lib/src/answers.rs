@@ -0,0 +1,88 @@
+use crate::runner::PartId;
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Where the accepted answer for one part of a day's real input is kept.
+pub fn known_answer_path(workspace_root: &Path, year: u16, day: u16, part: PartId) -> PathBuf {
+  workspace_root.join(format!("{year}/input/day{day:0>2}.real.answer.{part}.txt"))
+}
+
+/// Blank lines at the start and end of an answer are never meaningful,
+/// same as with input files. Multi-line answers often start with one.
+pub fn normalize(answer: &str) -> &str {
+  answer.trim_matches('\n')
+}
+
+/// Whether two answers are the same, optionally ignoring whitespace at the end of each line,
+/// which is easy to get wrong when drawing letters in a grid.
+pub fn same_answer(a: &str, b: &str, ignore_trailing_whitespace: bool) -> bool {
+  let (a, b) = (normalize(a), normalize(b));
+  if !ignore_trailing_whitespace {
+    return a == b;
+  }
+  a.trim_end()
+    .lines()
+    .map(str::trim_end)
+    .eq(b.trim_end().lines().map(str::trim_end))
+}
+
+/// The previously accepted answer, if one was ever recorded.
+pub fn read_known_answer(
+  workspace_root: &Path,
+  year: u16,
+  day: u16,
+  part: PartId,
+) -> Result<Option<String>> {
+  let path = known_answer_path(workspace_root, year, day, part);
+  if !path.exists() {
+    return Ok(None);
+  }
+  let contents =
+    fs::read_to_string(&path).with_context(|| format!("Failed to read known answer {path:?}"))?;
+  Ok(Some(normalize(&contents).to_string()))
+}
+
+pub fn record_answer(
+  workspace_root: &Path,
+  year: u16,
+  day: u16,
+  part: PartId,
+  answer: &str,
+) -> Result<()> {
+  let path = known_answer_path(workspace_root, year, day, part);
+  fs::write(&path, format!("{}\n", normalize(answer)))
+    .with_context(|| format!("Failed to record known answer {path:?}"))
+}
+
+/// Where the most recent answer computed for the real input is kept, for `aoc submit`.
+pub fn latest_answer_path(workspace_root: &Path, year: u16, day: u16, part: PartId) -> PathBuf {
+  workspace_root.join(format!("{year}/input/day{day:0>2}.real.latest.{part}.txt"))
+}
+
+pub fn read_latest_answer(
+  workspace_root: &Path,
+  year: u16,
+  day: u16,
+  part: PartId,
+) -> Result<Option<String>> {
+  let path = latest_answer_path(workspace_root, year, day, part);
+  if !path.exists() {
+    return Ok(None);
+  }
+  let contents =
+    fs::read_to_string(&path).with_context(|| format!("Failed to read latest answer {path:?}"))?;
+  Ok(Some(normalize(&contents).to_string()))
+}
+
+pub fn record_latest_answer(
+  workspace_root: &Path,
+  year: u16,
+  day: u16,
+  part: PartId,
+  answer: &str,
+) -> Result<()> {
+  let path = latest_answer_path(workspace_root, year, day, part);
+  fs::write(&path, format!("{}\n", normalize(answer)))
+    .with_context(|| format!("Failed to record latest answer {path:?}"))
+}
@@ -1,11 +1,16 @@
+use crate::answers;
 use crate::color::*;
+use crate::credentials;
+use crate::http::{self, AocClient, Endpoint};
 use crate::paths::{RelevantPaths, get_relevant_paths};
+use crate::puzzle::{self, ExtractedSample};
+use crate::runner::PartId;
 use anyhow::{Context, Result, bail};
 use jiff::{Unit, Zoned, ZonedDifference, civil::date};
+use regex::Regex;
 use std::fs;
-use std::io::{self, Write};
 use std::os::unix::fs::MetadataExt;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::{thread, time::Duration};
 
 type TaskResult = Result<Option<String>>;
@@ -15,7 +20,9 @@ type TaskResult = Result<Option<String>>;
 /// * If that day's code is not yet set up, creates it first.
 /// * If that day's input is not yet downloaded, checks if it's available and then downloads it if so.
 /// * If the input is not available yet, waits until it is and shows a countdown clock until then.
-pub fn setup(year: u16, day: u16, workspace_root: &Path) -> Result<()> {
+/// * Saves the puzzle page and extracts its examples into sample files, again for part 2 once part 1 is solved.
+/// * Downloads with the session cookie stored for `account`, see `credentials`.
+pub fn setup(year: u16, day: u16, workspace_root: &Path, account: &str) -> Result<()> {
   let this_year = Zoned::now()
     .year()
     .try_into()
@@ -39,9 +46,10 @@ pub fn setup(year: u16, day: u16, workspace_root: &Path) -> Result<()> {
   let setup_required = !paths.src_bin.exists()
     || !paths.year_input.exists()
     || need_file(&paths.year_cargo_toml)
-    || need_file(&paths.year_cookie)
+    || !paths.year_lib_rs.exists()
     || need_file(&paths.day_rs)
-    || need_file(&paths.real_input);
+    || need_file(&paths.real_input)
+    || samples_pending(year, day, &paths, workspace_root);
 
   if !setup_required {
     return Ok(());
@@ -57,9 +65,13 @@ pub fn setup(year: u16, day: u16, workspace_root: &Path) -> Result<()> {
       maybe_init_day(year, day, &paths).with_context(|| "Failed to init day {year}-{day:0>2}")
     }),
     Box::new(|| {
-      maybe_download_input(year, day, &paths)
+      maybe_download_input(year, day, &paths, workspace_root, account)
         .with_context(|| "Failed to download input for {year} day {day:0>2}")
     }),
+    Box::new(|| {
+      maybe_extract_samples(year, day, &paths, workspace_root, account)
+        .with_context(|| format!("Failed to extract samples for {year} day {day:0>2}"))
+    }),
   ];
 
   for task in tasks.iter() {
@@ -71,19 +83,6 @@ pub fn setup(year: u16, day: u16, workspace_root: &Path) -> Result<()> {
   Ok(())
 }
 
-fn ask_for_cookie(cookie_path: &Path) -> Result<()> {
-  print!(" • Paste your session cookie and press Enter: ");
-  io::stdout().flush()?;
-  let mut cookie = String::new();
-  io::stdin()
-    .read_line(&mut cookie)
-    .context("Tried to read a line from stdin and was not able")?;
-
-  fs::write(cookie_path, cookie.trim()).context("Failed to write cookie file")?;
-
-  Ok(())
-}
-
 fn maybe_init_year(year: u16, paths: &RelevantPaths) -> TaskResult {
   let mut changed_something = false;
 
@@ -108,8 +107,12 @@ fn maybe_init_year(year: u16, paths: &RelevantPaths) -> TaskResult {
     changed_something = true;
   }
 
-  if !paths.year_cookie.exists() {
-    ask_for_cookie(&paths.year_cookie)?;
+  if !paths.year_lib_rs.exists() {
+    let template =
+      fs::read_to_string(&paths.template_lib_rs).context("Failed to read lib.rs template")?;
+    let contents = template.replace("%YEAR%", year.to_string().as_str());
+    fs::write(&paths.year_lib_rs, contents)
+      .with_context(|| format!("Could not create {:?}", paths.year_lib_rs))?;
     changed_something = true;
   }
 
@@ -134,10 +137,33 @@ fn maybe_init_day(year: u16, day: u16, paths: &RelevantPaths) -> TaskResult {
     println!(
       "{GREEN} ✓{RESET} Created {GREEN}{UNDERLINE}{year}{BRIGHT_BLACK}/src/bin/{GREEN}{BOLD}day{day:0>2}.rs{RESET}"
     );
+    register_day(day, paths)?;
   }
   Ok(None)
 }
 
+/// Adds a new day to the year's `register_days!` list, so it can run in-process too.
+fn register_day(day: u16, paths: &RelevantPaths) -> Result<()> {
+  let lib_rs = fs::read_to_string(&paths.year_lib_rs)
+    .with_context(|| format!("Could not read {:?}", paths.year_lib_rs))?;
+  let entry = format!("  day{day:0>2} = \"bin/day{day:0>2}.rs\",\n");
+  if lib_rs.contains(entry.as_str()) {
+    return Ok(());
+  }
+  let Some(start) = lib_rs.find("advent_lib::register_days!(") else {
+    return Ok(());
+  };
+  let Some(end) = lib_rs[start..].find("\n);").map(|i| start + i + 1) else {
+    bail!(
+      "Could not find the end of register_days! in {:?}",
+      paths.year_lib_rs
+    );
+  };
+  let contents = format!("{}{entry}{}", &lib_rs[..end], &lib_rs[end..]);
+  fs::write(&paths.year_lib_rs, contents)
+    .with_context(|| format!("Could not update {:?}", paths.year_lib_rs))
+}
+
 fn wait_for_input_available(year: u16, day: u16) -> Result<bool> {
   // Puzzles unlock at midnight on the east coast of the USA.
   let unlock_datetime = date(year as i16, 12, day as i8)
@@ -167,7 +193,71 @@ fn wait_for_input_available(year: u16, day: u16) -> Result<bool> {
   }
 }
 
-fn maybe_download_input(year: u16, day: u16, paths: &RelevantPaths) -> TaskResult {
+const MAX_EARLY_RETRIES: usize = 6;
+
+enum Download {
+  Body(String),
+  /// The session cookie was rejected, so it needs replacing.
+  StaleCookie,
+  /// The server says the puzzle isn't unlocked yet.
+  TooEarly,
+}
+
+fn download(client: &AocClient, path: &str, cookie: &str, endpoint: Endpoint) -> Result<Download> {
+  let response = client.get(path, cookie, endpoint)?;
+  let (status, body) = (response.status, response.body);
+  // Checked first, since it comes with an error status too.
+  if body.contains("Please don't repeatedly request") {
+    Ok(Download::TooEarly)
+  } else if status == 500 || status == 400 || body.contains("Please log in") {
+    // A malformed cookie gets a 500, and a logged-out one gets a 400 asking to log in.
+    Ok(Download::StaleCookie)
+  } else if !(200..300).contains(&status) {
+    bail!("Server gave a {status} response:\n{body}");
+  } else {
+    Ok(Download::Body(body))
+  }
+}
+
+/// Downloads a page that needs a login, asking for a new cookie if the stored one is stale.
+fn download_logged_in(
+  client: &AocClient,
+  path: &str,
+  endpoint: Endpoint,
+  workspace_root: &Path,
+  account: &str,
+) -> Result<String> {
+  let mut cookie = credentials::session_cookie(workspace_root, client, account)?;
+  let mut early_retries = 0;
+  loop {
+    match download(client, path, &cookie, endpoint)? {
+      Download::Body(body) => return Ok(body),
+      Download::StaleCookie => {
+        println!(" {RED}✕{RESET} The session cookie is stale, log in again and copy a new one");
+        cookie = credentials::ask_for_cookie(client, account)?;
+      }
+      // The countdown already waited, so the local clock must be a little ahead.
+      Download::TooEarly if early_retries < MAX_EARLY_RETRIES => {
+        early_retries += 1;
+        println!(
+          " {YELLOW}•{RESET} Server says the puzzle isn't unlocked yet, trying again shortly"
+        );
+        thread::sleep(Duration::from_secs(5));
+      }
+      Download::TooEarly => {
+        bail!("Server still says the puzzle isn't unlocked, is the clock right?")
+      }
+    }
+  }
+}
+
+fn maybe_download_input(
+  year: u16,
+  day: u16,
+  paths: &RelevantPaths,
+  workspace_root: &Path,
+  account: &str,
+) -> TaskResult {
   let mut changed_something = false;
 
   // Create the input files if needed, even before downloading the actual input.
@@ -186,7 +276,7 @@ fn maybe_download_input(year: u16, day: u16, paths: &RelevantPaths) -> TaskResul
   let real_in = paths.year_input.join(format!("day{day:0>2}.real.txt"));
 
   if !sample_in.exists() {
-    fs::write(sample_in, "SAMPLE").expect("Could not write sample input");
+    fs::write(sample_in, PLACEHOLDER_SAMPLE).expect("Could not write sample input");
     fs::write(sample_out_1, "1").expect("Could not write sample output 1");
     fs::write(sample_out_2, "1").expect("Could not write sample output 2");
     changed_something = true;
@@ -208,27 +298,11 @@ fn maybe_download_input(year: u16, day: u16, paths: &RelevantPaths) -> TaskResul
     fs::read_to_string(&real_in).with_context(|| "Input file {real_in:?} went missing")?;
 
   if real_in_contents.is_empty() || real_in_contents.contains("Please don't repeatedly request") {
-    let cookie = fs::read_to_string(&paths.year_cookie).context("Cookie file went missing")?;
-    let client = reqwest::blocking::Client::new();
-    let response = client
-      .get(format!("https://adventofcode.com/{year}/day/{day}/input"))
-      .header("Cookie", format!("session={cookie}"))
-      .send()
-      .context("Failed to download input file")?;
-
-    if response.status() == 500 {
-      // TODO: Ask the user to input it again, then repeat, instead of crashing.
-      bail!("Server gave a 500 response, cookie is likely stale");
-    } else {
-      let body = response.text().expect("Could not get body text properly");
-      if body.contains("Please log in") {
-        // TODO: Ask the user to input it again, then repeat, instead of crashing.
-        bail!("Server says you aren't logged in, cookie is likely stale");
-      } else {
-        fs::write(&real_in, body.clone())
-          .with_context(|| format!("Failed to save body to file:\n{body}"))?;
-      }
-    }
+    let client = AocClient::new(account)?;
+    let path = format!("/{year}/day/{day}/input");
+    let body = download_logged_in(&client, &path, http::INPUT, workspace_root, account)?;
+    fs::write(&real_in, body.clone())
+      .with_context(|| format!("Failed to save body to file:\n{body}"))?;
     changed_something = true;
   }
 
@@ -238,3 +312,284 @@ fn maybe_download_input(year: u16, day: u16, paths: &RelevantPaths) -> TaskResul
     Ok(None)
   }
 }
+
+/// What the sample file says until the examples from the puzzle page replace it.
+const PLACEHOLDER_SAMPLE: &str = "SAMPLE";
+
+fn sample_path(paths: &RelevantPaths, day: u16, number: usize, suffix: &str) -> PathBuf {
+  paths
+    .year_input
+    .join(format!("day{day:0>2}.test{number:0>2}.{suffix}.txt"))
+}
+
+/// The numbers of the `testNN` samples that exist for this day, in order.
+fn sample_numbers(paths: &RelevantPaths, day: u16) -> Vec<usize> {
+  let re = Regex::new(&format!(r"^day{day:0>2}\.test(\d+)\.sample\.txt$")).unwrap();
+  let mut numbers: Vec<usize> = fs::read_dir(&paths.year_input)
+    .into_iter()
+    .flatten()
+    .flatten()
+    .filter_map(|entry| {
+      let name = entry.file_name();
+      re.captures(name.to_str()?)?[1].parse().ok()
+    })
+    .collect();
+  numbers.sort_unstable();
+  numbers
+}
+
+fn has_placeholder_sample(paths: &RelevantPaths, day: u16) -> bool {
+  fs::read_to_string(sample_path(paths, day, 1, "sample")).is_ok_and(|s| s == PLACEHOLDER_SAMPLE)
+}
+
+/// How many parts of the puzzle page samples were taken from. Only saving the page (as `aoc read` does)
+/// doesn't count. A page saved before this was kept counts as read once its placeholder sample is gone.
+fn samples_extracted(paths: &RelevantPaths, day: u16) -> usize {
+  if let Ok(parts) = fs::read_to_string(&paths.samples_extracted) {
+    return parts
+      .split_whitespace()
+      .next()
+      .and_then(|n| n.parse().ok())
+      .unwrap_or(0);
+  }
+  if has_placeholder_sample(paths, day) {
+    return 0;
+  }
+  fs::read_to_string(&paths.puzzle_page).map_or(0, |page| puzzle::day_articles(&page).len())
+}
+
+/// Whether the samples were last taken from a page fetched after part 1 was solved.
+/// That page should have had part 2, so if it didn't, fetching it again won't help.
+fn fetched_after_solving(paths: &RelevantPaths) -> bool {
+  fs::read_to_string(&paths.samples_extracted)
+    .is_ok_and(|parts| parts.split_whitespace().nth(1) == Some(SOLVED_MARK))
+}
+
+/// Follows the count in the samples-extracted file once part 1 was solved before the page was fetched.
+const SOLVED_MARK: &str = "solved";
+
+fn saved_page_has_part_2(paths: &RelevantPaths) -> bool {
+  fs::read_to_string(&paths.puzzle_page).is_ok_and(|page| puzzle::day_articles(&page).len() >= 2)
+}
+
+/// The page hasn't been read for samples yet, or part 2's samples are still to be taken from it.
+/// That's the case when the saved page has part 2, or when part 1 has been solved (and its answer recorded),
+/// which is when part 2's text appears on the site. The page is only fetched again once for that:
+/// if a page fetched after solving still has no part 2 (because the answer was accepted without solving it
+/// on the site, say), it isn't fetched again.
+fn samples_pending(year: u16, day: u16, paths: &RelevantPaths, workspace_root: &Path) -> bool {
+  match samples_extracted(paths, day) {
+    0 => return has_placeholder_sample(paths, day),
+    1 => {}
+    _ => return false,
+  }
+  saved_page_has_part_2(paths)
+    || (answers::known_answer_path(workspace_root, year, day, PartId::P1).exists()
+      && !fetched_after_solving(paths))
+}
+
+fn write_sample(
+  paths: &RelevantPaths,
+  day: u16,
+  number: usize,
+  sample: &ExtractedSample,
+  part: PartId,
+) -> Result<()> {
+  let sample_in = sample_path(paths, day, number, "sample");
+  fs::write(&sample_in, &sample.input).with_context(|| format!("Could not write {sample_in:?}"))?;
+  if let Some(answer) = &sample.answer {
+    let sample_out = sample_path(paths, day, number, &format!("expect.{part}"));
+    fs::write(&sample_out, answer).with_context(|| format!("Could not write {sample_out:?}"))?;
+  }
+  Ok(())
+}
+
+fn maybe_extract_samples(
+  year: u16,
+  day: u16,
+  paths: &RelevantPaths,
+  workspace_root: &Path,
+  account: &str,
+) -> TaskResult {
+  if !samples_pending(year, day, paths, workspace_root) {
+    return Ok(None);
+  }
+
+  let extracted = samples_extracted(paths, day);
+  let (html, saved) = match fs::read_to_string(&paths.puzzle_page) {
+    // Saved by `aoc read` before any samples were taken from it.
+    Ok(html) if extracted == 0 => (html, false),
+    // Saved with part 2 since its samples were last taken, by `aoc read` say.
+    Ok(html) if puzzle::day_articles(&html).len() >= 2 => (html, false),
+    // Once part 1 is solved, a cached copy of the page would still be missing part 2.
+    Ok(_) => (
+      save_puzzle_page(year, day, workspace_root, account, true)?,
+      true,
+    ),
+    Err(_) => (
+      save_puzzle_page(year, day, workspace_root, account, false)?,
+      true,
+    ),
+  };
+  let articles = puzzle::day_articles(&html);
+  let mut found = vec![];
+
+  if has_placeholder_sample(paths, day)
+    && let Some(article) = articles.first()
+  {
+    let samples = puzzle::extract_samples(article);
+    if !samples.is_empty() {
+      for suffix in ["sample", "expect.1", "expect.2"] {
+        let _ = fs::remove_file(sample_path(paths, day, 1, suffix));
+      }
+      for (i, sample) in samples.iter().enumerate() {
+        write_sample(paths, day, i + 1, sample, PartId::P1)?;
+      }
+      found.push(format!("{} part 1 sample(s)", samples.len()));
+    }
+  }
+
+  if extracted < 2
+    && let Some(article) = articles.get(1)
+  {
+    let samples = puzzle::extract_samples(article);
+    let existing = sample_numbers(paths, day);
+    if !samples.is_empty() {
+      let first = existing.last().map_or(1, |n| n + 1);
+      for (i, sample) in samples.iter().enumerate() {
+        write_sample(paths, day, first + i, sample, PartId::P2)?;
+      }
+      found.push(format!("{} part 2 sample(s)", samples.len()));
+    } else if let Some(answer) = puzzle::emphasized_answer(article) {
+      // Part 2 usually asks about the same example as part 1, without repeating it.
+      let reused = existing.into_iter().find(|&n| {
+        sample_path(paths, day, n, "expect.1").exists()
+          && !sample_path(paths, day, n, "expect.2").exists()
+      });
+      if let Some(n) = reused {
+        fs::write(sample_path(paths, day, n, "expect.2"), answer)
+          .context("Could not write part 2 expectation")?;
+        found.push(format!("the part 2 answer for test{n:0>2}"));
+      }
+    }
+  }
+
+  let solved = answers::known_answer_path(workspace_root, year, day, PartId::P1).exists();
+  let parts = if saved && solved {
+    format!("{} {SOLVED_MARK}", articles.len())
+  } else {
+    articles.len().to_string()
+  };
+  fs::write(&paths.samples_extracted, parts)
+    .with_context(|| format!("Could not write {:?}", paths.samples_extracted))?;
+
+  let description = if saved {
+    "Saved the puzzle description"
+  } else {
+    "Read the saved puzzle description"
+  };
+  Ok(Some(if found.is_empty() {
+    format!("{description}, but found no samples in it")
+  } else {
+    format!(
+      "{description}, and extracted {} from it",
+      found.join(" and ")
+    )
+  }))
+}
+
+/// Downloads the puzzle page, and keeps both the page and its description as Markdown next to the inputs.
+/// With `fresh`, skips the cached copy of the page.
+pub fn save_puzzle_page(
+  year: u16,
+  day: u16,
+  workspace_root: &Path,
+  account: &str,
+  fresh: bool,
+) -> Result<String> {
+  let paths = get_relevant_paths(year, day, workspace_root);
+  let client = AocClient::new(account)?;
+  let path = format!("/{year}/day/{day}");
+  if fresh {
+    client.invalidate(&path)?;
+  }
+  let html = download_logged_in(&client, &path, http::PUZZLE_PAGE, workspace_root, account)?;
+  fs::write(&paths.puzzle_page, &html)
+    .with_context(|| format!("Could not write {:?}", paths.puzzle_page))?;
+  fs::write(
+    &paths.puzzle_description,
+    puzzle::description_markdown(&html),
+  )
+  .with_context(|| format!("Could not write {:?}", paths.puzzle_description))?;
+  Ok(html)
+}
+
+/// The saved description of a day's puzzle, as Markdown.
+/// Only goes online if nothing was saved yet, or if `refresh` asks for part 2 that was unlocked since.
+pub fn read_description(
+  year: u16,
+  day: u16,
+  workspace_root: &Path,
+  account: &str,
+  refresh: bool,
+) -> Result<String> {
+  let paths = get_relevant_paths(year, day, workspace_root);
+  if !refresh {
+    if let Ok(markdown) = fs::read_to_string(&paths.puzzle_description) {
+      return Ok(markdown);
+    }
+    if let Ok(html) = fs::read_to_string(&paths.puzzle_page) {
+      return Ok(puzzle::description_markdown(&html));
+    }
+  }
+  if !paths.year_input.exists() {
+    bail!("There is no workspace for {year} yet, run `aoc -y {year} {day}` first");
+  }
+  let html = save_puzzle_page(year, day, workspace_root, account, refresh)?;
+  Ok(puzzle::description_markdown(&html))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use tempfile::TempDir;
+
+  const PART_1: &str = "<article class=\"day-desc\">Part 1</article>";
+  const BOTH_PARTS: &str =
+    "<article class=\"day-desc\">Part 1</article><article class=\"day-desc\">Part 2</article>";
+
+  #[test]
+  fn part_2_samples_wait_for_the_page_to_have_part_2() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path();
+    let paths = get_relevant_paths(2015, 1, root);
+    fs::create_dir_all(&paths.year_input).unwrap();
+    let pending = || samples_pending(2015, 1, &paths, root);
+
+    // Nothing read yet, and only the placeholder sample.
+    fs::write(sample_path(&paths, 1, 1, "sample"), PLACEHOLDER_SAMPLE).unwrap();
+    assert!(pending());
+
+    // Part 1's samples taken, and part 1 not solved.
+    fs::write(sample_path(&paths, 1, 1, "sample"), "(())").unwrap();
+    fs::write(&paths.puzzle_page, PART_1).unwrap();
+    fs::write(&paths.samples_extracted, "1").unwrap();
+    assert!(!pending());
+
+    // Solved, so the page gets fetched again.
+    fs::write(answers::known_answer_path(root, 2015, 1, PartId::P1), "3").unwrap();
+    assert!(pending());
+
+    // Fetched again after solving, but still without part 2.
+    fs::write(&paths.samples_extracted, "1 solved").unwrap();
+    assert_eq!(samples_extracted(&paths, 1), 1);
+    assert!(!pending());
+
+    // Saved with part 2 some other way, by `aoc read` say.
+    fs::write(&paths.puzzle_page, BOTH_PARTS).unwrap();
+    assert!(pending());
+
+    fs::write(&paths.samples_extracted, "2").unwrap();
+    assert!(!pending());
+  }
+}
@@ -0,0 +1,254 @@
+//! Session cookies for Advent of Code, kept in one place outside the workspace.
+//!
+//! Each account's cookie is its own file, `$XDG_CONFIG_HOME/advent-of-code/{account}.cookie`
+//! (or under `~/.config` without that variable), readable only by the current user.
+//! Cookies that older versions of this tool kept in each year's `input/cookie.txt` are moved here.
+use crate::color::*;
+use crate::http::{self, AocClient};
+use anyhow::{Context, Result, bail};
+use std::env;
+use std::fs::{self, DirBuilder, OpenOptions, Permissions};
+use std::io::{self, Write};
+use std::os::unix::fs::{DirBuilderExt, OpenOptionsExt, PermissionsExt};
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_ACCOUNT: &str = "default";
+
+pub fn store_dir() -> Result<PathBuf> {
+  if let Some(config) = env::var_os("XDG_CONFIG_HOME").filter(|v| !v.is_empty()) {
+    return Ok(PathBuf::from(config).join("advent-of-code"));
+  }
+  let home = env::var_os("HOME").context("Neither XDG_CONFIG_HOME nor HOME is set")?;
+  Ok(PathBuf::from(home).join(".config/advent-of-code"))
+}
+
+fn cookie_path(store: &Path, account: &str) -> Result<PathBuf> {
+  if account.is_empty()
+    || !account
+      .chars()
+      .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+  {
+    bail!("Account names can only use letters, numbers, '-' and '_', not {account:?}");
+  }
+  Ok(store.join(format!("{account}.cookie")))
+}
+
+fn save_cookie(store: &Path, account: &str, cookie: &str) -> Result<()> {
+  DirBuilder::new()
+    .recursive(true)
+    .mode(0o700)
+    .create(store)
+    .with_context(|| format!("Could not create {store:?}"))?;
+  fs::set_permissions(store, Permissions::from_mode(0o700))
+    .with_context(|| format!("Could not restrict permissions on {store:?}"))?;
+
+  let path = cookie_path(store, account)?;
+  let mut file = OpenOptions::new()
+    .write(true)
+    .create(true)
+    .truncate(true)
+    .mode(0o600)
+    .open(&path)
+    .with_context(|| format!("Could not open {path:?}"))?;
+  // The mode above only applies when the file is new.
+  file.set_permissions(Permissions::from_mode(0o600))?;
+  file
+    .write_all(cookie.as_bytes())
+    .with_context(|| format!("Could not write {path:?}"))
+}
+
+/// The per-year `input/cookie.txt` files that older versions of this tool created, newest year first.
+fn legacy_cookie_files(workspace_root: &Path) -> Vec<PathBuf> {
+  let mut years: Vec<u16> = fs::read_dir(workspace_root)
+    .into_iter()
+    .flatten()
+    .flatten()
+    .filter_map(|entry| entry.file_name().to_str()?.parse().ok())
+    .collect();
+  years.sort_unstable_by(|a, b| b.cmp(a));
+  years
+    .into_iter()
+    .map(|year| workspace_root.join(format!("{year}/input/cookie.txt")))
+    .filter(|path| path.exists())
+    .collect()
+}
+
+/// Moves the newest per-year cookie into the store for this account, and deletes all of them.
+fn migrate_legacy_cookies(
+  workspace_root: &Path,
+  store: &Path,
+  account: &str,
+) -> Result<Option<String>> {
+  let legacy = legacy_cookie_files(workspace_root);
+  let Some(newest) = legacy.first() else {
+    return Ok(None);
+  };
+  let cookie = fs::read_to_string(newest).with_context(|| format!("Could not read {newest:?}"))?;
+  let cookie = cookie.trim().to_string();
+  if cookie.is_empty() {
+    return Ok(None);
+  }
+  save_cookie(store, account, &cookie)?;
+  for path in legacy.iter() {
+    fs::remove_file(path).with_context(|| format!("Could not remove {path:?}"))?;
+  }
+  println!(
+    " {GREEN}✓ {RESET}Moved the session cookie from {newest:?} to {:?}",
+    cookie_path(store, account)?
+  );
+  Ok(Some(cookie))
+}
+
+/// Checks a session cookie with a cheap request that only succeeds when logged in.
+/// The settings page redirects to the login page for anyone else.
+pub fn cookie_is_valid(client: &AocClient, cookie: &str) -> Result<bool> {
+  let response = client
+    .get("/settings", cookie, http::SETTINGS)
+    .context("Failed to check the session cookie")?;
+  Ok(response.is_success())
+}
+
+/// Asks for a session cookie until `client` accepts one, then saves it for this account.
+pub fn ask_for_cookie(client: &AocClient, account: &str) -> Result<String> {
+  let store = store_dir()?;
+  loop {
+    print!(" • Paste the session cookie for account {YELLOW}{account}{RESET} and press Enter: ");
+    io::stdout().flush()?;
+    let mut cookie = String::new();
+    let read = io::stdin()
+      .read_line(&mut cookie)
+      .context("Tried to read a line from stdin and was not able")?;
+    if read == 0 {
+      bail!("No session cookie was given");
+    }
+    let cookie = cookie.trim();
+    if cookie.is_empty() {
+      continue;
+    }
+
+    if cookie_is_valid(client, cookie)? {
+      save_cookie(&store, account, cookie)?;
+      return Ok(cookie.to_string());
+    }
+    println!(" {RED}✕{RESET} That cookie isn't logged in to Advent of Code, try again");
+  }
+}
+
+/// The stored cookie for this account. If there isn't one yet, migrates an old per-year cookie,
+/// or asks for one and checks it with `client`.
+pub fn session_cookie(workspace_root: &Path, client: &AocClient, account: &str) -> Result<String> {
+  let store = store_dir()?;
+  let path = cookie_path(&store, account)?;
+  if path.exists() {
+    let cookie = fs::read_to_string(&path).with_context(|| format!("Could not read {path:?}"))?;
+    if !cookie.trim().is_empty() {
+      return Ok(cookie.trim().to_string());
+    }
+  }
+  if account == DEFAULT_ACCOUNT
+    && let Some(cookie) = migrate_legacy_cookies(workspace_root, &store, account)?
+  {
+    return Ok(cookie);
+  }
+  ask_for_cookie(client, account)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::stub_server::StubServer;
+  use tempfile::TempDir;
+
+  fn mode(path: &Path) -> u32 {
+    fs::metadata(path).unwrap().permissions().mode() & 0o777
+  }
+
+  fn write_legacy(workspace: &Path, year: u16, cookie: &str) -> PathBuf {
+    let path = workspace.join(format!("{year}/input/cookie.txt"));
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, cookie).unwrap();
+    path
+  }
+
+  #[test]
+  fn account_names_are_checked() {
+    let store = Path::new("/store");
+    assert_eq!(
+      cookie_path(store, "work_2-b").unwrap(),
+      store.join("work_2-b.cookie")
+    );
+    for bad in ["", "../default", "a b", "x.cookie"] {
+      assert!(cookie_path(store, bad).is_err(), "{bad:?}");
+    }
+  }
+
+  #[test]
+  fn saved_cookies_are_private() {
+    let dir = TempDir::new().unwrap();
+    let store = dir.path().join("advent-of-code");
+    save_cookie(&store, DEFAULT_ACCOUNT, "first").unwrap();
+    let path = cookie_path(&store, DEFAULT_ACCOUNT).unwrap();
+    assert_eq!(mode(&store), 0o700);
+    assert_eq!(mode(&path), 0o600);
+
+    // An existing file that's too open is tightened when it's overwritten.
+    fs::set_permissions(&path, Permissions::from_mode(0o644)).unwrap();
+    save_cookie(&store, DEFAULT_ACCOUNT, "second").unwrap();
+    assert_eq!(fs::read_to_string(&path).unwrap(), "second");
+    assert_eq!(mode(&path), 0o600);
+  }
+
+  #[test]
+  fn migrates_the_newest_legacy_cookie() {
+    let workspace = TempDir::new().unwrap();
+    let store = TempDir::new().unwrap();
+    let old = write_legacy(workspace.path(), 2016, "old");
+    let new = write_legacy(workspace.path(), 2019, " new\n");
+    fs::create_dir_all(workspace.path().join("2018/input")).unwrap();
+
+    let cookie = migrate_legacy_cookies(workspace.path(), store.path(), "work").unwrap();
+    assert_eq!(cookie.as_deref(), Some("new"));
+    assert!(!old.exists() && !new.exists());
+    let path = cookie_path(store.path(), "work").unwrap();
+    assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+    assert_eq!(mode(&path), 0o600);
+
+    // Nothing left to migrate.
+    assert_eq!(
+      migrate_legacy_cookies(workspace.path(), store.path(), "work").unwrap(),
+      None
+    );
+  }
+
+  #[test]
+  fn empty_legacy_cookies_are_left_alone() {
+    let workspace = TempDir::new().unwrap();
+    let store = TempDir::new().unwrap();
+    let legacy = write_legacy(workspace.path(), 2015, "\n");
+    assert_eq!(
+      migrate_legacy_cookies(workspace.path(), store.path(), DEFAULT_ACCOUNT).unwrap(),
+      None
+    );
+    assert!(legacy.exists());
+    assert!(!cookie_path(store.path(), DEFAULT_ACCOUNT).unwrap().exists());
+  }
+
+  #[test]
+  fn cookies_are_checked_against_the_clients_server() {
+    let cache_root = TempDir::new().unwrap();
+    let logged_in = StubServer::start(&[("/settings", 200, "settings")]);
+    let client = AocClient::with_base_url("test", &logged_in.base_url)
+      .unwrap()
+      .with_cache_root(cache_root.path());
+    assert!(cookie_is_valid(&client, "good").unwrap());
+    let requests = logged_in.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].headers["cookie"], "session=good");
+
+    let logged_out = StubServer::start(&[("/settings", 302, "")]);
+    let client = AocClient::with_base_url("test", &logged_out.base_url)
+      .unwrap()
+      .with_cache_root(cache_root.path());
+    assert!(!cookie_is_valid(&client, "stale").unwrap());
+  }
+}
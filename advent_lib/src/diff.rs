@@ -0,0 +1,205 @@
+//! Shows how a wrong answer differs from the expected one, which matters most for
+//! multi-line answers like letters drawn in a grid.
+use crate::color::*;
+
+enum Line<'a> {
+  Same(&'a str),
+  Removed(&'a str),
+  Added(&'a str),
+}
+
+/// The edits that turn `expected` into `received`.
+///
+/// Answers with the same number of lines are usually the same grid with some cells wrong,
+/// so those are compared row by row. Otherwise, this finds the longest common subsequence of lines,
+/// and answers are small enough that the quadratic table for that is no concern.
+fn diff_lines<'a>(expected: &[&'a str], received: &[&'a str]) -> Vec<Line<'a>> {
+  if expected.len() == received.len() {
+    let mut out = Vec::with_capacity(expected.len());
+    for (e, r) in expected.iter().zip(received.iter()) {
+      if e == r {
+        out.push(Line::Same(e));
+      } else {
+        out.push(Line::Removed(e));
+        out.push(Line::Added(r));
+      }
+    }
+    return out;
+  }
+
+  let (n, m) = (expected.len(), received.len());
+  // common[i][j] is the LCS length of expected[i..] and received[j..].
+  let mut common = vec![vec![0_usize; m + 1]; n + 1];
+  for i in (0..n).rev() {
+    for j in (0..m).rev() {
+      common[i][j] = if expected[i] == received[j] {
+        common[i + 1][j + 1] + 1
+      } else {
+        common[i + 1][j].max(common[i][j + 1])
+      };
+    }
+  }
+
+  let mut out = Vec::with_capacity(n.max(m));
+  let (mut i, mut j) = (0, 0);
+  while i < n || j < m {
+    if i < n && j < m && expected[i] == received[j] {
+      out.push(Line::Same(expected[i]));
+      i += 1;
+      j += 1;
+    } else if j < m && (i == n || common[i][j + 1] >= common[i + 1][j]) {
+      out.push(Line::Added(received[j]));
+      j += 1;
+    } else {
+      out.push(Line::Removed(expected[i]));
+      i += 1;
+    }
+  }
+  out
+}
+
+/// Colors the background of every character in `line` that isn't the same in `other` at the same position.
+/// Grid answers keep their shape, so comparing by position points right at the wrong cells.
+pub(crate) fn highlight_changes(line: &str, other: &str, background: &str) -> String {
+  let other: Vec<char> = other.chars().collect();
+  let mut out = String::with_capacity(line.len());
+  for (i, c) in line.chars().enumerate() {
+    if other.get(i) == Some(&c) {
+      out.push(c);
+    } else {
+      out.push_str(background);
+      out.push(c);
+      out.push_str(RESET_BG);
+    }
+  }
+  out
+}
+
+/// One colored line per line of the two answers: unchanged lines dimmed, the expected
+/// version of each changed line marked `-` and the received version marked `+`.
+pub(crate) fn render_diff(
+  expected: &str,
+  received: &str,
+  ignore_trailing_whitespace: bool,
+) -> Vec<String> {
+  let split = |answer: &'_ str| -> Vec<String> {
+    answer
+      .lines()
+      .map(|line| {
+        if ignore_trailing_whitespace {
+          line.trim_end().to_string()
+        } else {
+          line.to_string()
+        }
+      })
+      .collect()
+  };
+  let (expected, received) = (split(expected), split(received));
+  let expected: Vec<&str> = expected.iter().map(String::as_str).collect();
+  let received: Vec<&str> = received.iter().map(String::as_str).collect();
+
+  let mut out = vec![format!(
+    "   {GREEN}- Expected{RESET} {RED}+ Received{RESET}"
+  )];
+  // Each run of changed lines is shown in pairs, so a wrong row sits right under the right one.
+  fn flush(removed: &mut Vec<&str>, added: &mut Vec<&str>, out: &mut Vec<String>) {
+    for k in 0..removed.len().max(added.len()) {
+      match (removed.get(k), added.get(k)) {
+        (Some(e), Some(r)) => {
+          out.push(format!(
+            "   {GREEN}-{RESET} {}",
+            highlight_changes(e, r, BG_GREEN)
+          ));
+          out.push(format!(
+            "   {RED}+{RESET} {}",
+            highlight_changes(r, e, BG_RED)
+          ));
+        }
+        (Some(e), None) => out.push(format!("   {GREEN}-{RESET} {BG_GREEN}{e}{RESET_BG}")),
+        (None, Some(r)) => out.push(format!("   {RED}+{RESET} {BG_RED}{r}{RESET_BG}")),
+        (None, None) => unreachable!(),
+      }
+    }
+    removed.clear();
+    added.clear();
+  }
+  let mut removed = vec![];
+  let mut added = vec![];
+  for line in diff_lines(&expected, &received) {
+    match line {
+      Line::Same(line) => {
+        flush(&mut removed, &mut added, &mut out);
+        out.push(format!("     {BRIGHT_BLACK}{line}{RESET}"));
+      }
+      Line::Removed(line) => removed.push(line),
+      Line::Added(line) => added.push(line),
+    }
+  }
+  flush(&mut removed, &mut added, &mut out);
+  out
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// The diff without its colors, and without the header line.
+  fn plain(expected: &str, received: &str, ignore_trailing_whitespace: bool) -> Vec<String> {
+    let lines = render_diff(expected, received, ignore_trailing_whitespace);
+    assert!(lines[0].contains("Expected") && lines[0].contains("Received"));
+    lines[1..]
+      .iter()
+      .map(|line| {
+        let mut out = String::new();
+        let mut chars = line.chars();
+        while let Some(c) = chars.next() {
+          if c == '\x1B' {
+            chars.by_ref().find(|&c| c == 'm');
+          } else {
+            out.push(c);
+          }
+        }
+        out.trim_end().to_string()
+      })
+      .collect()
+  }
+
+  #[test]
+  fn changed_lines_are_paired_up() {
+    assert_eq!(
+      plain("#..#\n#..#\n####", "#..#\n##.#\n####", false),
+      ["     #..#", "   - #..#", "   + ##.#", "     ####"]
+    );
+  }
+
+  #[test]
+  fn changed_cells_are_highlighted() {
+    let lines = render_diff("#..#", "##.#", false);
+    assert_eq!(
+      lines[1],
+      format!("   {GREEN}-{RESET} #{BG_GREEN}.{RESET_BG}.#")
+    );
+    assert_eq!(lines[2], format!("   {RED}+{RESET} #{BG_RED}#{RESET_BG}.#"));
+  }
+
+  #[test]
+  fn added_and_removed_lines() {
+    assert_eq!(
+      plain("one\ntwo\nthree", "one\nthree", false),
+      ["     one", "   - two", "     three"]
+    );
+    assert_eq!(
+      plain("one\nthree", "zero\none\nthree\nfour", false),
+      ["   + zero", "     one", "     three", "   + four"]
+    );
+  }
+
+  #[test]
+  fn trailing_whitespace_can_be_ignored() {
+    assert_eq!(plain("a \nb", "a\nb", true), ["     a", "     b"]);
+    assert_eq!(
+      plain("a \nb", "a\nb", false),
+      ["   - a", "   + a", "     b"]
+    );
+  }
+}
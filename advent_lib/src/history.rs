@@ -0,0 +1,370 @@
+//! How long each part took on the real input, run after run, so slowdowns can't scroll away unnoticed.
+//!
+//! Kept in `{year}/input/dayNN.timings.txt`, one tab-separated line per part per run:
+//! `{time}\t{commit}\t{part}\t{parse_ns}\t{solve_ns}`, where the commit is from `git describe --always --dirty`.
+//! A part that reused the other part's parse has `reused` instead of `parse_ns`, so the parse is only counted once.
+//! Every run that solves the real input appends to it, and `aoc perf-report` reads it.
+use crate::color::*;
+use crate::report::duration_string;
+use crate::runner::PartId;
+use anyhow::{Context, Result, bail};
+use clap::ValueEnum;
+use jiff::Timestamp;
+use regex::Regex;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+#[derive(Clone, Debug)]
+pub struct TimingEntry {
+  pub time: Timestamp,
+  pub commit: String,
+  pub part: PartId,
+  /// Zero if the parse was reused.
+  pub parse: Duration,
+  pub parse_reused: bool,
+  pub solve: Duration,
+}
+
+impl TimingEntry {
+  pub fn total(&self) -> Duration {
+    self.parse + self.solve
+  }
+}
+
+pub fn history_path(workspace_root: &Path, year: u16, day: u16) -> PathBuf {
+  workspace_root.join(format!("{year}/input/day{day:0>2}.timings.txt"))
+}
+
+/// The commit the workspace is at, ending in `-dirty` if it has uncommitted changes.
+/// Only looked up once, since a whole year can run in one process.
+pub fn current_commit(workspace_root: &Path) -> String {
+  static COMMIT: OnceLock<String> = OnceLock::new();
+  COMMIT
+    .get_or_init(|| {
+      Command::new("git")
+        .current_dir(workspace_root)
+        .args(["describe", "--always", "--dirty"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+    })
+    .clone()
+}
+
+pub fn load(workspace_root: &Path, year: u16, day: u16) -> Result<Vec<TimingEntry>> {
+  let path = history_path(workspace_root, year, day);
+  if !path.exists() {
+    return Ok(vec![]);
+  }
+  let contents =
+    fs::read_to_string(&path).with_context(|| format!("Failed to read timings {path:?}"))?;
+  let mut entries = vec![];
+  for (i, line) in contents.lines().enumerate() {
+    if line.trim().is_empty() || line.starts_with('#') {
+      continue;
+    }
+    let fields: Vec<&str> = line.split('\t').collect();
+    let [time, commit, part, parse, solve] = fields[..] else {
+      bail!(
+        "Line {} of {path:?} should be time, commit, part, parse and solve nanoseconds, separated by tabs",
+        i + 1
+      );
+    };
+    let part = match part {
+      "1" => PartId::P1,
+      "2" => PartId::P2,
+      _ => bail!("Line {} of {path:?} has an unknown part {part:?}", i + 1),
+    };
+    let nanos = |field: &str| -> Result<Duration> {
+      Ok(Duration::from_nanos(field.parse().with_context(|| {
+        format!("Line {} of {path:?} has a bad duration {field:?}", i + 1)
+      })?))
+    };
+    let parse_reused = parse == "reused";
+    entries.push(TimingEntry {
+      time: time
+        .parse()
+        .with_context(|| format!("Line {} of {path:?} has a bad time {time:?}", i + 1))?,
+      commit: commit.to_string(),
+      part,
+      parse: if parse_reused {
+        Duration::ZERO
+      } else {
+        nanos(parse)?
+      },
+      parse_reused,
+      solve: nanos(solve)?,
+    });
+  }
+  Ok(entries)
+}
+
+/// `parse` is None when the part reused the other part's parse.
+pub fn append(
+  workspace_root: &Path,
+  year: u16,
+  day: u16,
+  part: PartId,
+  parse: Option<Duration>,
+  solve: Duration,
+) -> Result<()> {
+  let path = history_path(workspace_root, year, day);
+  let mut file = OpenOptions::new()
+    .create(true)
+    .append(true)
+    .open(&path)
+    .with_context(|| format!("Failed to open timings {path:?}"))?;
+  writeln!(
+    file,
+    "{}\t{}\t{part}\t{}\t{}",
+    Timestamp::now(),
+    current_commit(workspace_root),
+    parse.map_or("reused".to_string(), |parse| parse.as_nanos().to_string()),
+    solve.as_nanos()
+  )
+  .with_context(|| format!("Failed to write to timings {path:?}"))
+}
+
+/// Which earlier run `perf_report` compares the latest one to.
+#[derive(Clone, Copy, Debug, Default, PartialEq, ValueEnum)]
+pub enum Baseline {
+  /// The fastest earlier run
+  #[default]
+  Best,
+  /// The run just before the latest
+  Previous,
+}
+
+fn pick_baseline<'a>(earlier: &[&'a TimingEntry], against: Baseline) -> Option<&'a TimingEntry> {
+  match against {
+    Baseline::Best => earlier.iter().min_by_key(|e| e.total()).copied(),
+    Baseline::Previous => earlier.last().copied(),
+  }
+}
+
+/// Prints the latest time for each part of each day with recorded timings, next to the baseline run's time.
+/// Parts that got more than `threshold_percent` slower are flagged, and so is a year total over `budget`.
+/// Returns whether nothing was flagged.
+pub fn perf_report(
+  year: u16,
+  workspace_root: &Path,
+  threshold_percent: f64,
+  against: Baseline,
+  budget: Option<Duration>,
+) -> Result<bool> {
+  let input_dir = workspace_root.join(format!("{year}/input"));
+  let file_regex = Regex::new(r"^day(\d+)\.timings\.txt$").unwrap();
+  let mut days: Vec<u16> = if !input_dir.exists() {
+    vec![]
+  } else {
+    fs::read_dir(&input_dir)
+      .with_context(|| format!("Failed to read {input_dir:?}"))?
+      .filter_map(|entry| {
+        let name = entry.ok()?.file_name();
+        file_regex.captures(name.to_str()?)?[1].parse().ok()
+      })
+      .collect()
+  };
+  days.sort();
+  if days.is_empty() {
+    bail!("No timings have been recorded for {year} yet, run some days on their real input first");
+  }
+
+  let baseline_name = match against {
+    Baseline::Best => "Best",
+    Baseline::Previous => "Previous",
+  };
+  println!("⏱  {BOLD}Advent of Code {GREEN}{year}{RESET} timings ⏱");
+  println!("{BRIGHT_BLACK} Day Part │  Latest │ {baseline_name: >8} │  Change │ Commit{RESET}");
+  let mut all_good = true;
+  let mut year_total = Duration::ZERO;
+  for day in days {
+    let entries = load(workspace_root, year, day)?;
+    for part in [PartId::P1, PartId::P2] {
+      let for_part: Vec<&TimingEntry> = entries.iter().filter(|e| e.part == part).collect();
+      let Some((latest, earlier)) = for_part.split_last() else {
+        continue;
+      };
+      year_total += latest.total();
+      let baseline = pick_baseline(earlier, against);
+      let (baseline_str, change_str) = match baseline {
+        Some(baseline) => {
+          let change = latest.total().as_secs_f64() / baseline.total().as_secs_f64().max(1e-9);
+          let percent = (change - 1.0) * 100.0;
+          let change_str = if percent > threshold_percent {
+            all_good = false;
+            format!("{RED}{percent: >+6.0}% ✕{RESET}")
+          } else {
+            format!("{percent: >+6.0}%  ")
+          };
+          (duration_string(baseline.total()), change_str)
+        }
+        None => (
+          format!("{BRIGHT_BLACK}     -{RESET}"),
+          "         ".to_string(),
+        ),
+      };
+      let reused = if latest.parse_reused {
+        " (parse reused)"
+      } else {
+        ""
+      };
+      println!(
+        "  {BOLD}{day:0>2}{RESET}    {part} {BRIGHT_BLACK}│{RESET}  {} {BRIGHT_BLACK}│{RESET}   {baseline_str} {BRIGHT_BLACK}│{RESET} {change_str}{BRIGHT_BLACK}│{RESET} {BRIGHT_BLACK}{}{reused}{RESET}",
+        duration_string(latest.total()),
+        latest.commit
+      );
+    }
+  }
+
+  match budget {
+    Some(budget) if year_total > budget => {
+      all_good = false;
+      println!(
+        "⌛{BOLD}{BRIGHT_BLACK} Total:{RESET}{} {RED}over the budget of{RESET} {}",
+        duration_string(year_total),
+        duration_string(budget)
+      );
+    }
+    Some(budget) => println!(
+      "⌛{BOLD}{BRIGHT_BLACK} Total:{RESET}{} {GREEN}within the budget of{RESET} {}",
+      duration_string(year_total),
+      duration_string(budget)
+    ),
+    None => println!(
+      "⌛{BOLD}{BRIGHT_BLACK} Total:{RESET}{}",
+      duration_string(year_total)
+    ),
+  }
+  Ok(all_good)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn workspace(days: &[(u16, &str)]) -> tempfile::TempDir {
+    let root = tempfile::tempdir().unwrap();
+    fs::create_dir_all(root.path().join("2019/input")).unwrap();
+    for (day, contents) in days {
+      fs::write(history_path(root.path(), 2019, *day), contents).unwrap();
+    }
+    root
+  }
+
+  fn line(commit: &str, part: u8, parse: &str, solve: u64) -> String {
+    format!("2026-10-18T12:00:00Z\t{commit}\t{part}\t{parse}\t{solve}\n")
+  }
+
+  fn report(root: &Path, against: Baseline, budget: Option<Duration>) -> bool {
+    perf_report(2019, root, 20.0, against, budget).unwrap()
+  }
+
+  #[test]
+  fn loads_what_append_wrote() {
+    let root = workspace(&[]);
+    let ms = Duration::from_millis;
+    append(root.path(), 2019, 1, PartId::P1, Some(ms(2)), ms(3)).unwrap();
+    append(root.path(), 2019, 1, PartId::P2, None, ms(5)).unwrap();
+    let entries = load(root.path(), 2019, 1).unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].part, PartId::P1);
+    assert_eq!(entries[0].total(), ms(5));
+    assert!(!entries[0].parse_reused);
+    assert_eq!(entries[1].part, PartId::P2);
+    assert_eq!(entries[1].total(), ms(5));
+    assert!(entries[1].parse_reused);
+    assert!(load(root.path(), 2019, 2).unwrap().is_empty());
+  }
+
+  #[test]
+  fn a_malformed_line_is_an_error_naming_it() {
+    for (contents, error) in [
+      (
+        "2026-10-18T12:00:00Z\tabc\t1\t5\n",
+        "should be time, commit, part, parse and solve nanoseconds",
+      ),
+      (&line("abc", 3, "5", 5), "has an unknown part \"3\""),
+      (&line("abc", 1, "fast", 5), "has a bad duration \"fast\""),
+      ("yesterday\tabc\t1\t5\t5\n", "has a bad time \"yesterday\""),
+    ] {
+      let root = workspace(&[(1, &format!("# a comment\n\n{contents}"))]);
+      let message = load(root.path(), 2019, 1).unwrap_err().to_string();
+      assert!(message.starts_with("Line 3 of "), "{message}");
+      assert!(message.contains(error), "{message}");
+    }
+  }
+
+  #[test]
+  fn compares_against_the_best_or_the_previous_run() {
+    let earlier = [
+      line("a", 1, "0", 100),
+      line("b", 1, "0", 200),
+      line("c", 1, "0", 110),
+    ];
+    let entries: Vec<TimingEntry> = {
+      let root = workspace(&[(1, &earlier.concat())]);
+      load(root.path(), 2019, 1).unwrap()
+    };
+    let earlier: Vec<&TimingEntry> = entries.iter().collect();
+    assert_eq!(pick_baseline(&earlier, Baseline::Best).unwrap().commit, "a");
+    assert_eq!(
+      pick_baseline(&earlier, Baseline::Previous).unwrap().commit,
+      "c"
+    );
+    assert!(pick_baseline(&[], Baseline::Best).is_none());
+  }
+
+  #[test]
+  fn flags_parts_that_slowed_down_past_the_threshold() {
+    // 130 is 30% slower than the best run, but only 8% slower than the previous one.
+    let root = workspace(&[(
+      1,
+      &[
+        line("a", 1, "0", 100),
+        line("b", 1, "0", 120),
+        line("c", 1, "0", 130),
+      ]
+      .concat(),
+    )]);
+    assert!(!report(root.path(), Baseline::Best, None));
+    assert!(report(root.path(), Baseline::Previous, None));
+
+    let root = workspace(&[(
+      1,
+      &[line("a", 1, "0", 100), line("b", 1, "0", 119)].concat(),
+    )]);
+    assert!(report(root.path(), Baseline::Best, None));
+  }
+
+  #[test]
+  fn a_reused_parse_counts_once_toward_the_budget() {
+    // The parse is 600ns and each part solves in 100ns, so the day takes 800ns, not 1400ns.
+    let root = workspace(&[(
+      1,
+      &[line("a", 1, "600", 100), line("a", 2, "reused", 100)].concat(),
+    )]);
+    assert!(report(
+      root.path(),
+      Baseline::Best,
+      Some(Duration::from_nanos(800))
+    ));
+    assert!(!report(
+      root.path(),
+      Baseline::Best,
+      Some(Duration::from_nanos(799))
+    ));
+  }
+
+  #[test]
+  fn a_year_without_timings_is_an_error() {
+    let root = workspace(&[]);
+    assert!(perf_report(2019, root.path(), 20.0, Baseline::Best, None).is_err());
+  }
+}
@@ -0,0 +1,415 @@
+//! The one way this tool talks to Advent of Code.
+//!
+//! Every request identifies the tool in its User-Agent, waits out a minimum interval since the
+//! last request to the same endpoint (even from another run), and may be answered from an on-disk cache.
+//! Set `AOC_CONTACT` to add contact details to the User-Agent, as Advent of Code asks automated tools to,
+//! and `AOC_BASE_URL` to talk to a stand-in server instead.
+use crate::color::*;
+use anyhow::{Context, Result};
+use reqwest::blocking::Client;
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/NickLanam/advent-of-code";
+
+/// How long a response stays good in the cache.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Cache {
+  Never,
+  For(Duration),
+  Forever,
+}
+
+/// How politely to treat one kind of request.
+#[derive(Clone, Copy, Debug)]
+pub struct Endpoint {
+  pub min_interval: Duration,
+  pub cache: Cache,
+}
+
+/// Inputs never change once they exist.
+pub const INPUT: Endpoint = Endpoint {
+  min_interval: Duration::from_secs(5),
+  cache: Cache::Forever,
+};
+
+/// The page gains part 2 once part 1 is solved, so this is invalidated when that happens.
+pub const PUZZLE_PAGE: Endpoint = Endpoint {
+  min_interval: Duration::from_secs(5),
+  cache: Cache::For(Duration::from_secs(15 * 60)),
+};
+
+/// The server enforces its own wait between answers, this only keeps retries from hammering it.
+pub const ANSWER: Endpoint = Endpoint {
+  min_interval: Duration::from_secs(5),
+  cache: Cache::Never,
+};
+
+pub const SETTINGS: Endpoint = Endpoint {
+  min_interval: Duration::from_secs(1),
+  cache: Cache::Never,
+};
+
+pub struct Response {
+  pub status: u16,
+  pub body: String,
+}
+
+impl Response {
+  pub fn is_success(&self) -> bool {
+    (200..300).contains(&self.status)
+  }
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedResponse {
+  fetched_at: u64,
+  status: u16,
+  body: String,
+}
+
+pub struct AocClient {
+  base_url: String,
+  account: String,
+  client: Client,
+  cache_root: PathBuf,
+}
+
+fn cache_root() -> Result<PathBuf> {
+  if let Some(cache) = env::var_os("XDG_CACHE_HOME").filter(|v| !v.is_empty()) {
+    return Ok(PathBuf::from(cache).join("advent-of-code"));
+  }
+  let home = env::var_os("HOME").context("Neither XDG_CACHE_HOME nor HOME is set")?;
+  Ok(PathBuf::from(home).join(".cache/advent-of-code"))
+}
+
+/// Turns a path like `/2019/day/11/input` into `2019_day_11_input`, to name files after.
+fn file_key(path: &str) -> String {
+  path
+    .trim_matches('/')
+    .chars()
+    .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+    .collect()
+}
+
+fn now_secs() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .unwrap()
+    .as_secs()
+}
+
+impl AocClient {
+  /// Talks to `AOC_BASE_URL` if it's set, or the real site otherwise.
+  pub fn new(account: &str) -> Result<AocClient> {
+    let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+    AocClient::with_base_url(account, &base_url)
+  }
+
+  pub fn with_base_url(account: &str, base_url: &str) -> Result<AocClient> {
+    let user_agent = match env::var("AOC_CONTACT") {
+      Ok(contact) if !contact.trim().is_empty() => format!("{USER_AGENT} by {}", contact.trim()),
+      _ => USER_AGENT.to_string(),
+    };
+    let client = Client::builder()
+      .user_agent(user_agent)
+      // A redirect from Advent of Code means "log in first", which callers need to see.
+      .redirect(reqwest::redirect::Policy::none())
+      .build()
+      .context("Failed to create HTTP client")?;
+    Ok(AocClient {
+      base_url: base_url.trim_end_matches('/').to_string(),
+      account: account.to_string(),
+      client,
+      cache_root: cache_root()?,
+    })
+  }
+
+  /// Keeps the cache and the throttle's timestamps somewhere else, so tests don't share them.
+  #[cfg(test)]
+  pub(crate) fn with_cache_root(mut self, cache_root: &std::path::Path) -> AocClient {
+    self.cache_root = cache_root.to_path_buf();
+    self
+  }
+
+  /// Responses differ per account (inputs especially), so each account gets its own cache.
+  fn cache_path(&self, path: &str) -> PathBuf {
+    self
+      .cache_root
+      .join("responses")
+      .join(&self.account)
+      .join(file_key(&self.base_url))
+      .join(format!("{}.json", file_key(path)))
+  }
+
+  fn throttle_path(&self, path: &str) -> PathBuf {
+    self
+      .cache_root
+      .join("last-request")
+      .join(file_key(&self.base_url))
+      .join(file_key(path))
+  }
+
+  fn read_cache(&self, path: &str, cache: Cache) -> Option<Response> {
+    let max_age = match cache {
+      Cache::Never => return None,
+      Cache::For(max_age) => Some(max_age),
+      Cache::Forever => None,
+    };
+    let contents = fs::read_to_string(self.cache_path(path)).ok()?;
+    let cached: CachedResponse = serde_json::from_str(&contents).ok()?;
+    if let Some(max_age) = max_age
+      && now_secs().saturating_sub(cached.fetched_at) > max_age.as_secs()
+    {
+      return None;
+    }
+    Some(Response {
+      status: cached.status,
+      body: cached.body,
+    })
+  }
+
+  fn write_cache(&self, path: &str, response: &Response) -> Result<()> {
+    let cache_path = self.cache_path(path);
+    fs::create_dir_all(cache_path.parent().unwrap())
+      .with_context(|| format!("Could not create the cache directory for {cache_path:?}"))?;
+    let cached = CachedResponse {
+      fetched_at: now_secs(),
+      status: response.status,
+      body: response.body.clone(),
+    };
+    fs::write(&cache_path, serde_json::to_string(&cached)?)
+      .with_context(|| format!("Could not write {cache_path:?}"))
+  }
+
+  /// Forgets a cached response, for when the page is known to have changed.
+  pub fn invalidate(&self, path: &str) -> Result<()> {
+    let cache_path = self.cache_path(path);
+    if cache_path.exists() {
+      fs::remove_file(&cache_path).with_context(|| format!("Could not remove {cache_path:?}"))?;
+    }
+    Ok(())
+  }
+
+  /// Sleeps until the endpoint's minimum interval has passed since the last request to it.
+  fn throttle(&self, path: &str, min_interval: Duration) -> Result<()> {
+    let throttle_path = self.throttle_path(path);
+    let last = fs::read_to_string(&throttle_path)
+      .ok()
+      .and_then(|s| s.trim().parse::<u128>().ok());
+    if let Some(last) = last {
+      let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis();
+      let wait = (last + min_interval.as_millis()).saturating_sub(now);
+      if wait > 0 {
+        if wait >= 1000 {
+          println!(
+            " {YELLOW}•{RESET} Waiting {}s before requesting {path} again",
+            wait.div_ceil(1000)
+          );
+        }
+        thread::sleep(Duration::from_millis(wait as u64));
+      }
+    }
+    fs::create_dir_all(throttle_path.parent().unwrap())
+      .context("Could not create the throttle directory")?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis();
+    fs::write(&throttle_path, now.to_string())
+      .with_context(|| format!("Could not write {throttle_path:?}"))
+  }
+
+  fn finish(
+    &self,
+    path: &str,
+    endpoint: Endpoint,
+    response: reqwest::blocking::Response,
+  ) -> Result<Response> {
+    let response = Response {
+      status: response.status().as_u16(),
+      body: response
+        .text()
+        .context("Could not get body text properly")?,
+    };
+    // Only successes are worth repeating, an error is better retried.
+    if endpoint.cache != Cache::Never && response.is_success() {
+      self.write_cache(path, &response)?;
+    }
+    Ok(response)
+  }
+
+  /// GETs a path like `/2019/day/11/input`, from the cache if it's fresh enough.
+  pub fn get(&self, path: &str, cookie: &str, endpoint: Endpoint) -> Result<Response> {
+    if let Some(cached) = self.read_cache(path, endpoint.cache) {
+      return Ok(cached);
+    }
+    self.throttle(path, endpoint.min_interval)?;
+    let url = format!("{}{path}", self.base_url);
+    let response = self
+      .client
+      .get(&url)
+      .header("Cookie", format!("session={}", cookie.trim()))
+      .send()
+      .with_context(|| format!("Failed to request {url}"))?;
+    self.finish(path, endpoint, response)
+  }
+
+  /// POSTs a form, such as an answer. Never answered from the cache.
+  pub fn post_form(
+    &self,
+    path: &str,
+    cookie: &str,
+    form: &[(&str, &str)],
+    endpoint: Endpoint,
+  ) -> Result<Response> {
+    self.throttle(path, endpoint.min_interval)?;
+    let url = format!("{}{path}", self.base_url);
+    let response = self
+      .client
+      .post(&url)
+      .header("Cookie", format!("session={}", cookie.trim()))
+      .form(form)
+      .send()
+      .with_context(|| format!("Failed to post to {url}"))?;
+    self.finish(path, endpoint, response)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::stub_server::StubServer;
+  use std::time::Instant;
+  use tempfile::TempDir;
+
+  const CACHED: Endpoint = Endpoint {
+    min_interval: Duration::ZERO,
+    cache: Cache::For(Duration::from_secs(60)),
+  };
+
+  fn client(server: &StubServer, cache_root: &TempDir) -> AocClient {
+    AocClient::with_base_url("test", &server.base_url)
+      .unwrap()
+      .with_cache_root(cache_root.path())
+  }
+
+  /// Makes the cached response for `path` look like it was fetched `age` ago.
+  fn age_cache(client: &AocClient, path: &str, age: Duration) {
+    let cache_path = client.cache_path(path);
+    let mut cached: CachedResponse =
+      serde_json::from_str(&fs::read_to_string(&cache_path).unwrap()).unwrap();
+    cached.fetched_at -= age.as_secs();
+    fs::write(&cache_path, serde_json::to_string(&cached).unwrap()).unwrap();
+  }
+
+  #[test]
+  fn identifies_itself() {
+    let server = StubServer::start(&[("/2015/day/1", 200, "page")]);
+    let cache_root = TempDir::new().unwrap();
+    client(&server, &cache_root)
+      .get("/2015/day/1", " cookie\n", SETTINGS)
+      .unwrap();
+    let request = &server.requests()[0];
+    assert!(request.headers["user-agent"].starts_with(USER_AGENT));
+    assert_eq!(request.headers["cookie"], "session=cookie");
+  }
+
+  #[test]
+  fn fresh_responses_come_from_the_cache() {
+    let server = StubServer::start(&[("/2015/day/1", 200, "page")]);
+    let cache_root = TempDir::new().unwrap();
+    let client = client(&server, &cache_root);
+    for _ in 0..3 {
+      let response = client.get("/2015/day/1", "cookie", CACHED).unwrap();
+      assert_eq!((response.status, response.body.as_str()), (200, "page"));
+    }
+    assert_eq!(server.requests().len(), 1);
+  }
+
+  #[test]
+  fn cached_responses_expire() {
+    let server = StubServer::start(&[("/2015/day/1", 200, "page")]);
+    let cache_root = TempDir::new().unwrap();
+    let client = client(&server, &cache_root);
+    client.get("/2015/day/1", "cookie", CACHED).unwrap();
+    age_cache(&client, "/2015/day/1", Duration::from_secs(30));
+    client.get("/2015/day/1", "cookie", CACHED).unwrap();
+    assert_eq!(server.requests().len(), 1);
+    age_cache(&client, "/2015/day/1", Duration::from_secs(61));
+    client.get("/2015/day/1", "cookie", CACHED).unwrap();
+    assert_eq!(server.requests().len(), 2);
+  }
+
+  #[test]
+  fn inputs_never_expire() {
+    let server = StubServer::start(&[("/2015/day/1/input", 200, "(())")]);
+    let cache_root = TempDir::new().unwrap();
+    let client = client(&server, &cache_root);
+    client.get("/2015/day/1/input", "cookie", INPUT).unwrap();
+    age_cache(
+      &client,
+      "/2015/day/1/input",
+      Duration::from_secs(365 * 24 * 60 * 60),
+    );
+    let response = client.get("/2015/day/1/input", "cookie", INPUT).unwrap();
+    assert_eq!(response.body, "(())");
+    assert_eq!(server.requests().len(), 1);
+  }
+
+  #[test]
+  fn errors_and_uncached_endpoints_are_not_cached() {
+    let server = StubServer::start(&[("/settings", 200, "settings")]);
+    let cache_root = TempDir::new().unwrap();
+    let client = client(&server, &cache_root);
+    client.get("/settings", "cookie", SETTINGS).unwrap();
+    assert!(!client.cache_path("/settings").exists());
+    let missing = client.get("/2015/day/1", "cookie", CACHED).unwrap();
+    assert_eq!(missing.status, 404);
+    assert!(!missing.is_success());
+    assert!(!client.cache_path("/2015/day/1").exists());
+  }
+
+  #[test]
+  fn invalidate_forgets_the_cached_response() {
+    let server = StubServer::start(&[("/2015/day/1", 200, "page")]);
+    let cache_root = TempDir::new().unwrap();
+    let client = client(&server, &cache_root);
+    client.get("/2015/day/1", "cookie", CACHED).unwrap();
+    client.invalidate("/2015/day/1").unwrap();
+    client.get("/2015/day/1", "cookie", CACHED).unwrap();
+    assert_eq!(server.requests().len(), 2);
+    // Forgetting something that isn't cached is fine too.
+    client.invalidate("/2015/day/2").unwrap();
+  }
+
+  #[test]
+  fn throttle_waits_between_requests_to_one_path() {
+    let server = StubServer::start(&[
+      ("/2015/day/1/answer", 200, "a"),
+      ("/2015/day/2/answer", 200, "b"),
+    ]);
+    let cache_root = TempDir::new().unwrap();
+    let endpoint = Endpoint {
+      min_interval: Duration::from_millis(300),
+      cache: Cache::Never,
+    };
+    let start = Instant::now();
+    client(&server, &cache_root)
+      .post_form("/2015/day/1/answer", "cookie", &[], endpoint)
+      .unwrap();
+    // Another path isn't held up.
+    client(&server, &cache_root)
+      .post_form("/2015/day/2/answer", "cookie", &[], endpoint)
+      .unwrap();
+    assert!(start.elapsed() < Duration::from_millis(300));
+    // The wait holds across clients, like it does across runs.
+    client(&server, &cache_root)
+      .post_form("/2015/day/1/answer", "cookie", &[], endpoint)
+      .unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(300));
+    assert_eq!(server.requests().len(), 3);
+  }
+}
@@ -0,0 +1,282 @@
+//! Every answer submitted for a day, and what the server said about it.
+//!
+//! Kept in `{year}/input/dayNN.submissions.txt`, one tab-separated line per submission:
+//! `{part}\t{verdict}\t{answer}`, where the verdict is `correct`, `wrong`, `too-high` or `too-low`.
+//! `aoc submit` appends to it, and lines can be added by hand for answers submitted in the browser.
+use crate::runner::PartId;
+use anyhow::{Context, Result, bail};
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LedgerVerdict {
+  Correct,
+  Wrong,
+  TooHigh,
+  TooLow,
+}
+
+impl LedgerVerdict {
+  fn as_str(&self) -> &'static str {
+    match self {
+      LedgerVerdict::Correct => "correct",
+      LedgerVerdict::Wrong => "wrong",
+      LedgerVerdict::TooHigh => "too-high",
+      LedgerVerdict::TooLow => "too-low",
+    }
+  }
+
+  fn from_str(s: &str) -> Result<LedgerVerdict> {
+    Ok(match s {
+      "correct" => LedgerVerdict::Correct,
+      "wrong" => LedgerVerdict::Wrong,
+      "too-high" => LedgerVerdict::TooHigh,
+      "too-low" => LedgerVerdict::TooLow,
+      _ => bail!("Unknown verdict {s:?}"),
+    })
+  }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LedgerEntry {
+  pub part: PartId,
+  pub verdict: LedgerVerdict,
+  pub answer: String,
+}
+
+impl fmt::Display for LedgerEntry {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let verdict = match self.verdict {
+      LedgerVerdict::Correct => "correct",
+      LedgerVerdict::Wrong => "wrong",
+      LedgerVerdict::TooHigh => "too high",
+      LedgerVerdict::TooLow => "too low",
+    };
+    write!(f, "{} (part {}, {verdict})", self.answer, self.part)
+  }
+}
+
+#[derive(Debug, Default)]
+pub struct Ledger {
+  pub entries: Vec<LedgerEntry>,
+}
+
+pub fn ledger_path(workspace_root: &Path, year: u16, day: u16) -> PathBuf {
+  workspace_root.join(format!("{year}/input/day{day:0>2}.submissions.txt"))
+}
+
+/// Newlines would break the one-line-per-entry format, so multi-line answers store them escaped.
+fn escape(answer: &str) -> String {
+  answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(answer: &str) -> String {
+  let mut out = String::with_capacity(answer.len());
+  let mut chars = answer.chars();
+  while let Some(c) = chars.next() {
+    match (c, chars.clone().next()) {
+      ('\\', Some('n')) => {
+        out.push('\n');
+        chars.next();
+      }
+      ('\\', Some('\\')) => {
+        out.push('\\');
+        chars.next();
+      }
+      _ => out.push(c),
+    }
+  }
+  out
+}
+
+impl Ledger {
+  pub fn load(workspace_root: &Path, year: u16, day: u16) -> Result<Ledger> {
+    let path = ledger_path(workspace_root, year, day);
+    if !path.exists() {
+      return Ok(Ledger::default());
+    }
+    let contents =
+      fs::read_to_string(&path).with_context(|| format!("Failed to read ledger {path:?}"))?;
+    let mut entries = vec![];
+    for (i, line) in contents.lines().enumerate() {
+      if line.trim().is_empty() || line.starts_with('#') {
+        continue;
+      }
+      let mut fields = line.splitn(3, '\t');
+      let (Some(part), Some(verdict), Some(answer)) = (fields.next(), fields.next(), fields.next())
+      else {
+        bail!(
+          "Line {} of {path:?} should be part, verdict and answer, separated by tabs",
+          i + 1
+        );
+      };
+      let part = match part.trim() {
+        "1" => PartId::P1,
+        "2" => PartId::P2,
+        _ => bail!("Line {} of {path:?} has an unknown part {part:?}", i + 1),
+      };
+      let verdict = LedgerVerdict::from_str(verdict.trim())
+        .with_context(|| format!("Line {} of {path:?}", i + 1))?;
+      entries.push(LedgerEntry {
+        part,
+        verdict,
+        answer: unescape(answer),
+      });
+    }
+    Ok(Ledger { entries })
+  }
+
+  pub fn append(workspace_root: &Path, year: u16, day: u16, entry: &LedgerEntry) -> Result<()> {
+    let path = ledger_path(workspace_root, year, day);
+    let mut file = OpenOptions::new()
+      .create(true)
+      .append(true)
+      .open(&path)
+      .with_context(|| format!("Failed to open ledger {path:?}"))?;
+    writeln!(
+      file,
+      "{}\t{}\t{}",
+      entry.part,
+      entry.verdict.as_str(),
+      escape(&entry.answer)
+    )
+    .with_context(|| format!("Failed to write to ledger {path:?}"))
+  }
+
+  /// The earlier submission that shows this answer is wrong, if there is one:
+  /// the same answer already rejected, or a numeric answer outside the too-high/too-low bounds.
+  pub fn rules_out(&self, part: PartId, answer: &str) -> Option<&LedgerEntry> {
+    let for_part = || self.entries.iter().filter(move |e| e.part == part);
+    if let Some(entry) =
+      for_part().find(|e| e.answer == answer && e.verdict != LedgerVerdict::Correct)
+    {
+      return Some(entry);
+    }
+
+    let value: i128 = answer.trim().parse().ok()?;
+    let numeric = |e: &LedgerEntry| e.answer.trim().parse::<i128>().ok();
+    let lowest_too_high = for_part()
+      .filter(|e| e.verdict == LedgerVerdict::TooHigh)
+      .filter_map(|e| Some((numeric(e)?, e)))
+      .min_by_key(|(n, _)| *n);
+    if let Some((high, entry)) = lowest_too_high
+      && value >= high
+    {
+      return Some(entry);
+    }
+    let highest_too_low = for_part()
+      .filter(|e| e.verdict == LedgerVerdict::TooLow)
+      .filter_map(|e| Some((numeric(e)?, e)))
+      .max_by_key(|(n, _)| *n);
+    if let Some((low, entry)) = highest_too_low
+      && value <= low
+    {
+      return Some(entry);
+    }
+    None
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use tempfile::TempDir;
+
+  fn entry(part: PartId, verdict: LedgerVerdict, answer: &str) -> LedgerEntry {
+    LedgerEntry {
+      part,
+      verdict,
+      answer: answer.to_string(),
+    }
+  }
+
+  fn workspace() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir_all(dir.path().join("2015/input")).unwrap();
+    dir
+  }
+
+  #[test]
+  fn rules_out_answers_past_the_bounds() {
+    let ledger = Ledger {
+      entries: vec![
+        entry(PartId::P1, LedgerVerdict::TooHigh, "500"),
+        entry(PartId::P1, LedgerVerdict::TooHigh, "300"),
+        entry(PartId::P1, LedgerVerdict::TooLow, "100"),
+        entry(PartId::P1, LedgerVerdict::TooLow, "200"),
+      ],
+    };
+    assert_eq!(ledger.rules_out(PartId::P1, "300").unwrap().answer, "300");
+    assert_eq!(ledger.rules_out(PartId::P1, "400").unwrap().answer, "300");
+    assert_eq!(ledger.rules_out(PartId::P1, "200").unwrap().answer, "200");
+    assert_eq!(ledger.rules_out(PartId::P1, "150").unwrap().answer, "200");
+    assert_eq!(ledger.rules_out(PartId::P1, "250"), None);
+    assert_eq!(ledger.rules_out(PartId::P1, "ABC"), None);
+    // Bounds only apply to the part they were given for.
+    assert_eq!(ledger.rules_out(PartId::P2, "400"), None);
+  }
+
+  #[test]
+  fn rules_out_exact_rejected_answers_only() {
+    let ledger = Ledger {
+      entries: vec![
+        entry(PartId::P1, LedgerVerdict::Wrong, "HELLO"),
+        entry(PartId::P2, LedgerVerdict::Correct, "42"),
+      ],
+    };
+    let found = ledger.rules_out(PartId::P1, "HELLO").unwrap();
+    assert_eq!(found.verdict, LedgerVerdict::Wrong);
+    assert_eq!(ledger.rules_out(PartId::P1, "HELL"), None);
+    assert_eq!(ledger.rules_out(PartId::P2, "HELLO"), None);
+    assert_eq!(ledger.rules_out(PartId::P2, "42"), None);
+  }
+
+  #[test]
+  fn answers_round_trip_through_the_file() {
+    let dir = workspace();
+    let entries = [
+      entry(PartId::P1, LedgerVerdict::TooLow, "12"),
+      entry(PartId::P2, LedgerVerdict::Wrong, "#..#\n#..#\n####"),
+      entry(PartId::P2, LedgerVerdict::Wrong, "a\tb"),
+      entry(PartId::P2, LedgerVerdict::Correct, "back\\slash\\n"),
+    ];
+    for e in entries.iter() {
+      Ledger::append(dir.path(), 2015, 1, e).unwrap();
+    }
+    let contents = fs::read_to_string(ledger_path(dir.path(), 2015, 1)).unwrap();
+    assert_eq!(contents.lines().count(), entries.len());
+
+    let ledger = Ledger::load(dir.path(), 2015, 1).unwrap();
+    assert_eq!(ledger.entries, entries);
+  }
+
+  #[test]
+  fn load_skips_comments_and_rejects_bad_lines() {
+    let dir = workspace();
+    let path = ledger_path(dir.path(), 2015, 1);
+    assert!(
+      Ledger::load(dir.path(), 2015, 1)
+        .unwrap()
+        .entries
+        .is_empty()
+    );
+
+    fs::write(&path, "# submitted in the browser\n\n1\tcorrect\t7\n").unwrap();
+    let ledger = Ledger::load(dir.path(), 2015, 1).unwrap();
+    assert_eq!(
+      ledger.entries,
+      [entry(PartId::P1, LedgerVerdict::Correct, "7")]
+    );
+
+    fs::write(&path, "1\tcorrect\t7\n3\twrong\t8\n").unwrap();
+    let err = Ledger::load(dir.path(), 2015, 1).unwrap_err();
+    assert!(format!("{err:#}").contains("Line 2"), "{err:#}");
+    fs::write(&path, "1\tmaybe\t7\n").unwrap();
+    let err = Ledger::load(dir.path(), 2015, 1).unwrap_err();
+    assert!(format!("{err:#}").contains("Unknown verdict"), "{err:#}");
+    fs::write(&path, "1 correct 7\n").unwrap();
+    assert!(Ledger::load(dir.path(), 2015, 1).is_err());
+  }
+}
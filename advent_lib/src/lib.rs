@@ -1,8 +1,24 @@
 //! Advent of Code Library (`advent_lib`)
+pub mod answers;
 pub mod bootstrap;
 pub mod color;
+pub mod credentials;
 pub mod direction;
 pub mod grid;
+pub mod history;
+pub mod http;
+pub mod ledger;
+pub mod memory;
+pub mod ocr;
+pub mod params;
+pub mod puzzle;
+pub mod registry;
 pub mod runner;
+pub mod submit;
+pub mod watch;
 
+pub(crate) mod diff;
 pub(crate) mod paths;
+pub(crate) mod report;
+#[cfg(test)]
+pub(crate) mod stub_server;
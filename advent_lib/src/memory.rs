@@ -0,0 +1,98 @@
+//! A global allocator that counts what it allocates, so runs can report memory use next to their timings.
+//!
+//! It's opt-in: the `count-allocations` feature installs it in every binary that uses `advent_lib`,
+//! which is what `--memory` builds with. Any other binary can install it itself, as in
+//! `#[global_allocator] static ALLOCATOR: CountingAllocator = CountingAllocator;`.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// The system allocator, counting every allocation made on each thread.
+pub struct CountingAllocator;
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+// Per thread, so that a solver left running on another thread (like one that timed out) doesn't count.
+// None of these need dropping, so using them never allocates or fails, even while a thread exits.
+thread_local! {
+  static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+  static BYTES: Cell<u64> = const { Cell::new(0) };
+  static LIVE: Cell<u64> = const { Cell::new(0) };
+  static PEAK: Cell<u64> = const { Cell::new(0) };
+}
+
+fn allocated(size: usize) {
+  ALLOCATIONS.set(ALLOCATIONS.get() + 1);
+  BYTES.set(BYTES.get() + size as u64);
+  let live = LIVE.get() + size as u64;
+  LIVE.set(live);
+  PEAK.set(PEAK.get().max(live));
+}
+
+fn freed(size: usize) {
+  // Memory allocated on another thread can be freed on this one.
+  LIVE.set(LIVE.get().saturating_sub(size as u64));
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+  unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    let ptr = unsafe { System.alloc(layout) };
+    if !ptr.is_null() {
+      allocated(layout.size());
+    }
+    ptr
+  }
+
+  unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+    let ptr = unsafe { System.alloc_zeroed(layout) };
+    if !ptr.is_null() {
+      allocated(layout.size());
+    }
+    ptr
+  }
+
+  unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    unsafe { System.dealloc(ptr, layout) };
+    freed(layout.size());
+  }
+
+  /// Counts as freeing the old allocation and making a new one, since that's what it may have to do.
+  unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+    let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+    if !new_ptr.is_null() {
+      freed(layout.size());
+      allocated(new_size);
+    }
+    new_ptr
+  }
+}
+
+/// What was allocated on one thread while something ran.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocStats {
+  pub allocations: u64,
+  pub bytes: u64,
+  /// The most memory that was allocated and not yet freed at once, beyond what already was before.
+  pub peak: u64,
+}
+
+/// Whether the counting allocator is the global allocator. Before `main` runs, something has always allocated.
+pub fn is_counting() -> bool {
+  ALLOCATIONS.get() > 0
+}
+
+/// Runs `f`, and returns what it allocated on this thread. All zeroes if the counting allocator isn't installed.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, AllocStats) {
+  let allocations = ALLOCATIONS.get();
+  let bytes = BYTES.get();
+  let live = LIVE.get();
+  PEAK.set(live);
+  let result = f();
+  let stats = AllocStats {
+    allocations: ALLOCATIONS.get() - allocations,
+    bytes: BYTES.get() - bytes,
+    peak: PEAK.get().saturating_sub(live),
+  };
+  (result, stats)
+}
@@ -0,0 +1,403 @@
+//! Reads the block letters that some puzzles draw as their answer, so those days can return text.
+//!
+//! Advent of Code uses two fonts: letters 4 pixels wide and 6 tall (5 apart, Y is the only one that's wider),
+//! and letters 6 pixels wide and 10 tall (8 apart). The drawing's height decides which one it's read with.
+//! Each font only knows the letters that puzzle answers have been seen to use, so the tall one has no I, for one.
+use crate::grid::Infinite2dSet;
+use anyhow::{Result, bail};
+
+struct Font {
+  height: usize,
+  /// The distance from the left edge of one letter to the left edge of the next.
+  cell_width: usize,
+  glyphs: &'static [(char, &'static [&'static str])],
+}
+
+const SMALL: Font = Font {
+  height: 6,
+  cell_width: 5,
+  glyphs: &[
+    ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', &[".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', &["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+  ],
+};
+
+const LARGE: Font = Font {
+  height: 10,
+  cell_width: 8,
+  glyphs: &[
+    (
+      'A',
+      &[
+        "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+        "#....#",
+      ],
+    ),
+    (
+      'B',
+      &[
+        "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#",
+        "#####.",
+      ],
+    ),
+    (
+      'C',
+      &[
+        ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#",
+        ".####.",
+      ],
+    ),
+    (
+      'E',
+      &[
+        "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....",
+        "######",
+      ],
+    ),
+    (
+      'F',
+      &[
+        "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....",
+        "#.....",
+      ],
+    ),
+    (
+      'G',
+      &[
+        ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##",
+        ".###.#",
+      ],
+    ),
+    (
+      'H',
+      &[
+        "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#",
+        "#....#",
+      ],
+    ),
+    (
+      'J',
+      &[
+        "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.",
+        ".###..",
+      ],
+    ),
+    (
+      'K',
+      &[
+        "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.",
+        "#....#",
+      ],
+    ),
+    (
+      'L',
+      &[
+        "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+        "######",
+      ],
+    ),
+    (
+      'N',
+      &[
+        "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##",
+        "#....#",
+      ],
+    ),
+    (
+      'P',
+      &[
+        "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....",
+        "#.....",
+      ],
+    ),
+    (
+      'R',
+      &[
+        "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#",
+        "#....#",
+      ],
+    ),
+    (
+      'X',
+      &[
+        "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#",
+        "#....#",
+      ],
+    ),
+    (
+      'Z',
+      &[
+        "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....",
+        "######",
+      ],
+    ),
+  ],
+};
+
+impl Font {
+  fn glyph_matches(rows: &[&str], cell: &[Vec<bool>]) -> bool {
+    rows.iter().zip(cell.iter()).all(|(row, cell_row)| {
+      let mut glyph_row = row.chars().map(|c| c == '#');
+      // Past the end of the glyph, the rest of the cell is the space before the next letter.
+      cell_row
+        .iter()
+        .all(|&lit| glyph_row.next().unwrap_or(false) == lit)
+    })
+  }
+
+  fn recognize(&self, cell: &[Vec<bool>]) -> Option<char> {
+    self
+      .glyphs
+      .iter()
+      .find(|(_, rows)| Font::glyph_matches(rows, cell))
+      .map(|(c, _)| *c)
+  }
+}
+
+/// Draws a glyph that couldn't be read, for the error message.
+fn draw(cell: &[Vec<bool>]) -> String {
+  cell
+    .iter()
+    .map(|row| {
+      row
+        .iter()
+        .map(|&lit| if lit { '#' } else { '.' })
+        .collect::<String>()
+    })
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+/// Reads the letters in a grid of pixels, where `true` is lit. Blank rows and columns around the letters are ignored.
+pub fn read_pixels(pixels: &[Vec<bool>]) -> Result<String> {
+  let lit: Vec<(usize, usize)> = pixels
+    .iter()
+    .enumerate()
+    .flat_map(|(y, row)| {
+      row
+        .iter()
+        .enumerate()
+        .filter(|(_, lit)| **lit)
+        .map(move |(x, _)| (x, y))
+    })
+    .collect();
+  if lit.is_empty() {
+    bail!("There are no lit pixels to read letters from");
+  }
+  let min_x = lit.iter().map(|p| p.0).min().unwrap();
+  let max_x = lit.iter().map(|p| p.0).max().unwrap();
+  let min_y = lit.iter().map(|p| p.1).min().unwrap();
+  let max_y = lit.iter().map(|p| p.1).max().unwrap();
+
+  let height = max_y - min_y + 1;
+  let Some(font) = [SMALL, LARGE].into_iter().find(|f| f.height == height) else {
+    bail!("The letters are {height} pixels tall, but only 6 and 10 pixel tall letters can be read");
+  };
+  let pixel = |x: isize, y: usize| -> bool {
+    x >= 0
+      && pixels
+        .get(y)
+        .and_then(|row| row.get(x as usize))
+        .copied()
+        .unwrap_or(false)
+  };
+
+  // Some letters (like I) start with a blank column, so if the first letter is one of those,
+  // the cells only line up once they start a column or more to the left of the first lit pixel.
+  // If none of them work, the one that read the most letters says the most about what went wrong.
+  let mut best_failure: Option<(String, usize, usize, Vec<Vec<bool>>)> = None;
+  for offset in 0..font.cell_width {
+    let left = min_x as isize - offset as isize;
+    let count = (max_x - min_x + offset + 1).div_ceil(font.cell_width);
+    let mut text = String::new();
+    let mut unreadable = None;
+    for i in 0..count {
+      let cell_left = left + (i * font.cell_width) as isize;
+      let cell: Vec<Vec<bool>> = (min_y..=max_y)
+        .map(|y| {
+          (0..font.cell_width)
+            .map(|dx| pixel(cell_left + dx as isize, y))
+            .collect()
+        })
+        .collect();
+      match font.recognize(&cell) {
+        Some(c) => text.push(c),
+        None => {
+          text.push('?');
+          unreadable = unreadable.or(Some((i + 1, cell)));
+        }
+      }
+    }
+    match unreadable {
+      None => return Ok(text),
+      Some((n, cell)) => {
+        let unknown = |text: &str| text.matches('?').count();
+        if best_failure
+          .as_ref()
+          .is_none_or(|(best, ..)| unknown(&text) < unknown(best))
+        {
+          best_failure = Some((text, n, count, cell));
+        }
+      }
+    }
+  }
+  let (text, n, count, cell) = best_failure.unwrap();
+  bail!(
+    "Could not recognize letter {n} of {count} (read {text:?}):\n{}",
+    draw(&cell)
+  );
+}
+
+/// Reads the letters in a drawing like the ones puzzles print, where `#` or `█` is lit and anything else isn't.
+pub fn read_str(drawing: &str) -> Result<String> {
+  let pixels: Vec<Vec<bool>> = drawing
+    .lines()
+    .map(|line| line.chars().map(|c| c == '#' || c == '█').collect())
+    .collect();
+  read_pixels(&pixels)
+}
+
+/// Reads the letters formed by the points in a set, with y increasing downwards.
+pub fn read_set(points: &Infinite2dSet) -> Result<String> {
+  let Some(min_x) = points.keys().map(|(x, _)| x).min() else {
+    bail!("There are no lit pixels to read letters from");
+  };
+  let min_y = points.keys().map(|(_, y)| y).min().unwrap();
+  let max_x = points.keys().map(|(x, _)| x).max().unwrap();
+  let max_y = points.keys().map(|(_, y)| y).max().unwrap();
+  let pixels: Vec<Vec<bool>> = (min_y..=max_y)
+    .map(|y| (min_x..=max_x).map(|x| points.contains(x, y)).collect())
+    .collect();
+  read_pixels(&pixels)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn reads_small_letters() {
+    let drawing = "\
+#..#.####.#....#.....##.
+#..#.#....#....#....#..#
+####.###..#....#....#..#
+#..#.#....#....#....#..#
+#..#.#....#....#....#..#
+#..#.####.####.####..##.";
+    assert_eq!(read_str(drawing).unwrap(), "HELLO");
+  }
+
+  #[test]
+  fn reads_large_letters() {
+    let drawing = "\
+######....##....#####.
+.....#...#..#...#....#
+.....#..#....#..#....#
+....#...#....#..#....#
+...#....#....#..#####.
+..#.....######..#.....
+.#......#....#..#.....
+#.......#....#..#.....
+#.......#....#..#.....
+######..#....#..#.....";
+    assert_eq!(read_str(drawing).unwrap(), "ZAP");
+  }
+
+  #[test]
+  fn a_leading_letter_can_start_with_a_blank_column() {
+    // The first lit pixel is in I's second column, so the cells have to start one column to its left.
+    let drawing = "\
+.###.####
+..#..#...
+..#..###.
+..#..#...
+..#..#...
+.###.#...";
+    assert_eq!(read_str(drawing).unwrap(), "IF");
+  }
+
+  #[test]
+  fn y_is_wider_than_the_other_small_letters() {
+    let drawing = "\
+#....#...#####
+#....#...##...
+#.....#.#.###.
+#......#..#...
+#......#..#...
+####...#..####";
+    assert_eq!(read_str(drawing).unwrap(), "LYE");
+  }
+
+  #[test]
+  fn reads_a_set_anywhere_on_the_plane() {
+    let drawing = [
+      "#....#..#####.",
+      "#....#..#....#",
+      "#....#..#....#",
+      "#....#..#....#",
+      "######..#####.",
+      "#....#..#.....",
+      "#....#..#.....",
+      "#....#..#.....",
+      "#....#..#.....",
+      "#....#..#.....",
+    ];
+    let points: Infinite2dSet = drawing
+      .iter()
+      .enumerate()
+      .flat_map(|(y, row)| {
+        row
+          .char_indices()
+          .filter(|&(_, c)| c == '#')
+          .map(move |(x, _)| (x as i32 - 50, y as i32 - 20))
+      })
+      .collect();
+    assert_eq!(read_set(&points).unwrap(), "HP");
+  }
+
+  #[test]
+  fn an_unknown_letter_is_drawn_in_the_error() {
+    let drawing = "\
+#..#.####
+#..#.####
+####.####
+#..#.####
+#..#.####
+#..#.####";
+    let error = read_str(drawing).unwrap_err();
+    assert_eq!(
+      error.to_string(),
+      "Could not recognize letter 2 of 2 (read \"H?\"):\n####.\n####.\n####.\n####.\n####.\n####."
+    );
+  }
+
+  #[test]
+  fn only_the_two_font_heights_can_be_read() {
+    let drawing = "#\n#\n#\n#\n#\n#\n#\n#";
+    let error = read_str(drawing).unwrap_err();
+    assert_eq!(
+      error.to_string(),
+      "The letters are 8 pixels tall, but only 6 and 10 pixel tall letters can be read"
+    );
+    assert_eq!(
+      read_str("....").unwrap_err().to_string(),
+      "There are no lit pixels to read letters from"
+    );
+  }
+}
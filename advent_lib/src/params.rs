@@ -0,0 +1,152 @@
+//! Constants that differ between inputs, like a grid size or a step count that the puzzle text
+//! gives separately for the example.
+//!
+//! Each input can have a sidecar file next to it, `dayNN.{name}.params.toml` for a sample
+//! or `dayNN.real.params.toml` for the real input. The runner hands a solver's `parse`
+//! the parameters for the input being parsed, as in `params.get_or("deck_size", 10_007)?`.
+use anyhow::{Context, Result};
+use serde::de::DeserializeOwned;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Debug, Default)]
+pub struct Params {
+  table: toml::Table,
+  source: Option<PathBuf>,
+}
+
+pub fn params_path(workspace_root: &Path, year: u16, day: u16, name: &str) -> PathBuf {
+  workspace_root.join(format!("{year}/input/day{day:0>2}.{name}.params.toml"))
+}
+
+impl Params {
+  /// Empty parameters if the file doesn't exist, so every value falls back to its default.
+  pub fn load(path: &Path) -> Result<Params> {
+    if !path.exists() {
+      return Ok(Params::default());
+    }
+    let contents =
+      fs::read_to_string(path).with_context(|| format!("Failed to read params {path:?}"))?;
+    let table = contents
+      .parse::<toml::Table>()
+      .with_context(|| format!("Failed to parse params {path:?}"))?;
+    Ok(Params {
+      table,
+      source: Some(path.to_path_buf()),
+    })
+  }
+
+  fn source_name(&self) -> String {
+    match &self.source {
+      Some(path) => format!("{path:?}"),
+      None => "this input's params (there is no params file)".to_string(),
+    }
+  }
+
+  /// The value for `key`, or None if it isn't set. Fails if it's set to something that isn't a `T`.
+  pub fn get<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>> {
+    let Some(value) = self.table.get(key) else {
+      return Ok(None);
+    };
+    let value = value
+      .clone()
+      .try_into()
+      .with_context(|| format!("Param {key} in {} has the wrong type", self.source_name()))?;
+    Ok(Some(value))
+  }
+
+  pub fn get_or<T: DeserializeOwned>(&self, key: &str, default: T) -> Result<T> {
+    Ok(self.get(key)?.unwrap_or(default))
+  }
+
+  /// Like `get`, for values that have no sensible default.
+  pub fn require<T: DeserializeOwned>(&self, key: &str) -> Result<T> {
+    self
+      .get(key)?
+      .with_context(|| format!("Param {key} is not set in {}", self.source_name()))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn write_params(contents: &str) -> (tempfile::TempDir, PathBuf) {
+    let dir = tempfile::tempdir().unwrap();
+    let path = params_path(dir.path(), 2019, 22, "test01");
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, contents).unwrap();
+    (dir, path)
+  }
+
+  #[test]
+  fn sidecars_sit_next_to_each_input() {
+    let root = Path::new("/workspace");
+    assert_eq!(
+      params_path(root, 2019, 22, "test01"),
+      root.join("2019/input/day22.test01.params.toml")
+    );
+    assert_eq!(
+      params_path(root, 2018, 3, "real"),
+      root.join("2018/input/day03.real.params.toml")
+    );
+  }
+
+  #[test]
+  fn reads_values_from_the_sidecar() {
+    let (_dir, path) = write_params("deck_size = 10\nname = \"small\"\n");
+    let params = Params::load(&path).unwrap();
+    assert_eq!(params.get::<u64>("deck_size").unwrap(), Some(10));
+    assert_eq!(params.get_or("deck_size", 10_007_u64).unwrap(), 10);
+    assert_eq!(params.require::<String>("name").unwrap(), "small");
+    assert_eq!(params.get::<u64>("card").unwrap(), None);
+    assert_eq!(params.get_or("card", 2019_u64).unwrap(), 2019);
+  }
+
+  #[test]
+  fn a_missing_sidecar_falls_back_to_defaults() {
+    let dir = tempfile::tempdir().unwrap();
+    let params = Params::load(&params_path(dir.path(), 2019, 22, "real")).unwrap();
+    assert_eq!(params.get_or("deck_size", 10_007_u64).unwrap(), 10_007);
+    let error = params.require::<u64>("deck_size").unwrap_err();
+    assert_eq!(
+      error.to_string(),
+      "Param deck_size is not set in this input's params (there is no params file)"
+    );
+  }
+
+  #[test]
+  fn require_names_the_file_missing_the_key() {
+    let (_dir, path) = write_params("deck_size = 10\n");
+    let error = Params::load(&path)
+      .unwrap()
+      .require::<u64>("card")
+      .unwrap_err();
+    assert_eq!(
+      error.to_string(),
+      format!("Param card is not set in {path:?}")
+    );
+  }
+
+  #[test]
+  fn a_value_of_the_wrong_type_is_an_error_not_a_default() {
+    let (_dir, path) = write_params("deck_size = \"ten\"\n");
+    let params = Params::load(&path).unwrap();
+    let error = params.get_or("deck_size", 10_007_u64).unwrap_err();
+    assert_eq!(
+      error.to_string(),
+      format!("Param deck_size in {path:?} has the wrong type")
+    );
+    assert!(params.require::<u64>("deck_size").is_err());
+  }
+
+  #[test]
+  fn a_malformed_sidecar_fails_to_load() {
+    let (_dir, path) = write_params("deck_size = \n");
+    let error = Params::load(&path).unwrap_err();
+    assert_eq!(
+      error.to_string(),
+      format!("Failed to parse params {path:?}")
+    );
+  }
+}
@@ -0,0 +1,288 @@
+//! Reads the puzzle page for a day: the description of each part, and the examples in it.
+//! Everything here works on the page's HTML alone, so it can be checked against saved copies of pages.
+use crate::color::*;
+use regex::Regex;
+
+/// One `<pre><code>` block from the puzzle text.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExtractedSample {
+  pub input: String,
+  /// The emphasized `<code><em>` answer that the text gives after this example, if there is one.
+  pub answer: Option<String>,
+}
+
+/// The inner HTML of each `<article class="day-desc">`, part 1 first.
+/// Part 2 only appears on the page once part 1 is solved.
+pub fn day_articles(html: &str) -> Vec<&str> {
+  let re = Regex::new(r#"(?s)<article class="day-desc">(.*?)</article>"#).unwrap();
+  re.captures_iter(html)
+    .map(|caps| caps.get(1).unwrap().as_str())
+    .collect()
+}
+
+/// Every example block in one part's article, in order.
+/// The answer for each is the last emphasized code between it and the next block,
+/// since the text usually walks through the example and then states its result.
+pub fn extract_samples(article: &str) -> Vec<ExtractedSample> {
+  let re = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+  let blocks: Vec<_> = re.captures_iter(article).collect();
+  blocks
+    .iter()
+    .enumerate()
+    .map(|(i, caps)| {
+      let whole = caps.get(0).unwrap();
+      let until = blocks
+        .get(i + 1)
+        .map_or(article.len(), |next| next.get(0).unwrap().start());
+      ExtractedSample {
+        input: decode_entities(&strip_tags(caps.get(1).unwrap().as_str())),
+        answer: emphasized_answer(&article[whole.end()..until]),
+      }
+    })
+    .collect()
+}
+
+/// The last `<code><em>` (or `<em><code>`) in a stretch of HTML, which is how the puzzle text highlights results.
+pub fn emphasized_answer(html: &str) -> Option<String> {
+  let re = Regex::new(r"<code><em>([^<\n]*)</em></code>|<em><code>([^<\n]*)</code></em>").unwrap();
+  re.captures_iter(html)
+    .last()
+    .and_then(|caps| caps.get(1).or(caps.get(2)))
+    .map(|m| decode_entities(m.as_str()))
+    .filter(|answer| !answer.trim().is_empty())
+}
+
+pub fn strip_tags(html: &str) -> String {
+  Regex::new(r"<[^>]*>")
+    .unwrap()
+    .replace_all(html, "")
+    .to_string()
+}
+
+/// Only the entities that actually show up in puzzle pages.
+pub fn decode_entities(text: &str) -> String {
+  let re = Regex::new(r"&(#x[0-9a-fA-F]+|#[0-9]+|[a-z]+);").unwrap();
+  re.replace_all(text, |caps: &regex::Captures| {
+    let entity = &caps[1];
+    let decoded = match entity {
+      "lt" => Some('<'),
+      "gt" => Some('>'),
+      "amp" => Some('&'),
+      "quot" => Some('"'),
+      "apos" => Some('\''),
+      "nbsp" => Some(' '),
+      _ if entity.starts_with("#x") => u32::from_str_radix(&entity[2..], 16)
+        .ok()
+        .and_then(char::from_u32),
+      _ if entity.starts_with('#') => entity[1..].parse().ok().and_then(char::from_u32),
+      _ => None,
+    };
+    decoded.map_or_else(|| caps[0].to_string(), |c| c.to_string())
+  })
+  .to_string()
+}
+
+/// Both parts' descriptions (or just part 1's, before it's solved) as Markdown.
+pub fn description_markdown(html: &str) -> String {
+  let parts: Vec<String> = day_articles(html)
+    .into_iter()
+    .map(article_to_markdown)
+    .collect();
+  format!("{}\n", parts.join("\n\n"))
+}
+
+/// Converts the handful of tags that puzzle descriptions use.
+pub fn article_to_markdown(article: &str) -> String {
+  // Example blocks are taken out first, so the inline rules below can't touch them.
+  let pre_re = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+  let mut blocks = vec![];
+  let text = pre_re.replace_all(article, |caps: &regex::Captures| {
+    let code = decode_entities(&strip_tags(&caps[1]));
+    blocks.push(format!("```\n{}\n```", code.trim_end_matches('\n')));
+    format!("\n\n\u{0}{}\u{0}\n\n", blocks.len() - 1)
+  });
+
+  let rules = [
+    (r"(?s)<h2[^>]*>(.*?)</h2>", "\n\n## $1\n\n"),
+    (r"(?s)<code><em>(.*?)</em></code>", "**`$1`**"),
+    (r"(?s)<em><code>(.*?)</code></em>", "**`$1`**"),
+    (r"(?s)<em[^>]*>(.*?)</em>", "*$1*"),
+    (r"(?s)<code>(.*?)</code>", "`$1`"),
+    (r#"(?s)<a href="([^"]*)"[^>]*>(.*?)</a>"#, "[$2]($1)"),
+    (r"<li>", "- "),
+    (r"</li>\s*", "\n"),
+    (r"</?ul>", "\n\n"),
+    (r"</p>", "\n\n"),
+  ];
+  let mut text = text.to_string();
+  for (pattern, replacement) in rules {
+    text = Regex::new(pattern)
+      .unwrap()
+      .replace_all(&text, replacement)
+      .to_string();
+  }
+  let text = decode_entities(&strip_tags(&text));
+
+  let text = Regex::new(r"\n{3,}")
+    .unwrap()
+    .replace_all(text.trim(), "\n\n")
+    .to_string();
+  Regex::new(r"\x00(\d+)\x00")
+    .unwrap()
+    .replace_all(&text, |caps: &regex::Captures| {
+      blocks[caps[1].parse::<usize>().unwrap()].clone()
+    })
+    .to_string()
+}
+
+/// Colors the Markdown from `description_markdown` for reading in a terminal.
+pub fn render_markdown(markdown: &str) -> String {
+  let inline_rules = [
+    (
+      r"\*\*`([^`]*)`\*\*",
+      format!("{BOLD}{BRIGHT_YELLOW}$1{RESET}"),
+    ),
+    (r"`([^`]*)`", format!("{YELLOW}$1{RESET}")),
+    (r"\*([^*]+)\*", format!("{BOLD}$1{RESET}")),
+    (
+      r"\[([^\]]*)\]\([^)]*\)",
+      format!("{UNDERLINE}$1{RESET_UNDERLINE}"),
+    ),
+  ];
+  let inline_rules: Vec<_> = inline_rules
+    .into_iter()
+    .map(|(pattern, replacement)| (Regex::new(pattern).unwrap(), replacement))
+    .collect();
+
+  let mut out = vec![];
+  let mut in_block = false;
+  for line in markdown.lines() {
+    if line == "```" {
+      in_block = !in_block;
+    } else if in_block {
+      out.push(format!("    {BRIGHT_BLACK}{line}{RESET}"));
+    } else if let Some(heading) = line.strip_prefix("## ") {
+      out.push(format!("{BOLD}{GREEN}{heading}{RESET}"));
+    } else {
+      let mut line = line.to_string();
+      for (re, replacement) in inline_rules.iter() {
+        line = re.replace_all(&line, replacement.as_str()).to_string();
+      }
+      out.push(line);
+    }
+  }
+  out.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const PART_1_PAGE: &str = include_str!("../fixtures/puzzle_part1.html");
+  const BOTH_PARTS_PAGE: &str = include_str!("../fixtures/puzzle_both_parts.html");
+
+  #[test]
+  fn articles_for_each_unlocked_part() {
+    assert_eq!(day_articles(PART_1_PAGE).len(), 1);
+    assert_eq!(day_articles(BOTH_PARTS_PAGE).len(), 2);
+    assert_eq!(
+      day_articles(PART_1_PAGE)[0],
+      day_articles(BOTH_PARTS_PAGE)[0]
+    );
+  }
+
+  #[test]
+  fn samples_with_their_answers() {
+    assert_eq!(
+      extract_samples(day_articles(PART_1_PAGE)[0]),
+      vec![
+        ExtractedSample {
+          input: "(())\n".to_string(),
+          answer: None,
+        },
+        ExtractedSample {
+          input: ")())())\n".to_string(),
+          answer: Some("-3".to_string()),
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn samples_are_decoded() {
+    let article = "<pre><code>a &lt;<em>b</em>&gt; &amp;c\n</code></pre><p>gives <code><em>&lt;3</em></code></p>";
+    assert_eq!(
+      extract_samples(article),
+      vec![ExtractedSample {
+        input: "a <b> &c\n".to_string(),
+        answer: Some("<3".to_string()),
+      }]
+    );
+  }
+
+  #[test]
+  fn part_2_without_samples() {
+    let part_2 = day_articles(BOTH_PARTS_PAGE)[1];
+    assert!(extract_samples(part_2).is_empty());
+    // Part 2 asks about examples without repeating them as blocks.
+    assert_eq!(emphasized_answer(part_2), Some("5".to_string()));
+  }
+
+  #[test]
+  fn emphasized_answer_is_the_last_one() {
+    assert_eq!(
+      emphasized_answer("<code><em>1</em></code> then <em><code>2</code></em>"),
+      Some("2".to_string())
+    );
+    assert_eq!(
+      emphasized_answer("<em>what floor</em> and <code>0</code>"),
+      None
+    );
+    assert_eq!(emphasized_answer("<code><em> </em></code>"), None);
+  }
+
+  #[test]
+  fn part_1_as_markdown() {
+    assert_eq!(
+      article_to_markdown(day_articles(PART_1_PAGE)[0]),
+      r#"## --- Day 1: Not Quite Lisp ---
+
+Santa is trying to deliver presents in a large apartment building, but he can't find the right floor - the directions he got are a little confusing. He starts on the ground floor (floor `0`) and then follows the instructions one character at a time.
+
+An opening parenthesis, `(`, means he should go up one floor, and a closing parenthesis, `)`, means he should go down one floor.
+
+For example:
+
+```
+(())
+```
+
+and `()()` both result in floor `0`, while this one:
+
+```
+)())())
+```
+
+results in floor **`-3`**.
+
+To *what floor* do the instructions take Santa? Floors can be ["below"](https://en.wikipedia.org/wiki/Basement) the ground & go on forever."#
+    );
+  }
+
+  #[test]
+  fn part_2_as_markdown() {
+    assert_eq!(
+      article_to_markdown(day_articles(BOTH_PARTS_PAGE)[1]),
+      "## --- Part Two ---
+
+Now, given the same instructions, find the *position* of the first character that causes him to enter the basement (floor `-1`).  The first character in the instructions has position `1`.
+
+For example:
+
+- `)` causes him to enter the basement at character position `1`.
+- `()())` causes him to enter the basement at character position **`5`**.
+
+What is the *position* of the character that causes Santa to first enter the basement?"
+    );
+  }
+}
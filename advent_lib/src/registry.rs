@@ -0,0 +1,74 @@
+//! Lets a year's solutions run as a library, in the same process as the caller,
+//! instead of as one child binary per day.
+//!
+//! Each solution ends with `advent_lib::solution!(...)` instead of writing its own `main`.
+//! That gives it a `pub fn run_solution`, which the year's `lib.rs` collects with `advent_lib::register_days!`.
+use crate::runner::{DaySummary, RunOptions};
+use anyhow::Result;
+
+/// Runs one day's solver in this process, with the given options.
+pub type DayFn = fn(&RunOptions) -> Result<DaySummary>;
+
+/// One solution in a year's registry.
+pub struct RegisteredDay {
+  pub day: u16,
+  pub run: DayFn,
+  /// Set when the day declares its own `TIME_LIMIT`, because it can run away on bad input.
+  /// `--all` runs it as a child process instead, so that work it times out on ends with that process.
+  pub own_process: bool,
+}
+
+/// Every solution in one year's crate, in day order.
+pub struct YearRegistry {
+  pub year: u16,
+  pub days: &'static [RegisteredDay],
+}
+
+impl YearRegistry {
+  pub fn get(&self, day: u16) -> Option<DayFn> {
+    self.days.iter().find(|d| d.day == day).map(|d| d.run)
+  }
+}
+
+/// Replaces a solution's `main`, as in `advent_lib::solution!(Solver {}, 2019, 11);`.
+/// The binary still works exactly like before, and the year's library can call `run_solution` directly.
+#[macro_export]
+macro_rules! solution {
+  ($solver:expr, $year:literal, $day:literal) => {
+    pub const DAY: u16 = $day;
+    pub const OWN_PROCESS: bool = $crate::runner::declares_time_limit(&$solver);
+
+    pub fn run_solution(
+      options: &$crate::runner::RunOptions,
+    ) -> ::anyhow::Result<$crate::runner::DaySummary> {
+      $crate::runner::Day::run_with_options(&$solver, $year, $day, options)
+    }
+
+    // When this file is compiled as part of the year's library, nothing calls this.
+    #[allow(dead_code)]
+    fn main() -> ::anyhow::Result<()> {
+      $crate::runner::run_from_command_line(run_solution)
+    }
+  };
+}
+
+/// Compiles a year's solution binaries into its library as well, and lists them in `DAYS`,
+/// as in `advent_lib::register_days!(2019, day01 = "bin/day01.rs", day02 = "bin/day02.rs");`.
+#[macro_export]
+macro_rules! register_days {
+  ($year:literal, $($module:ident = $path:literal),* $(,)?) => {
+    $(
+      #[path = $path]
+      pub mod $module;
+    )*
+
+    pub const DAYS: $crate::registry::YearRegistry = $crate::registry::YearRegistry {
+      year: $year,
+      days: &[$($crate::registry::RegisteredDay {
+        day: $module::DAY,
+        run: $module::run_solution,
+        own_process: $module::OWN_PROCESS,
+      }),*],
+    };
+  };
+}
@@ -0,0 +1,831 @@
+use crate::answers;
+use crate::color::*;
+use crate::diff::{highlight_changes, render_diff};
+use crate::ledger::LedgerEntry;
+use crate::memory::AllocStats;
+use crate::runner::{OutputFormat, PartId};
+use serde::Serialize;
+use std::cell::RefCell;
+use std::time::Duration;
+
+pub(crate) fn duration_string(duration: Duration) -> String {
+  if duration.as_micros() < 1 {
+    format!("{: >4}{CYAN}ns{RESET}", duration.subsec_nanos())
+  } else if duration.as_millis() < 1 {
+    format!("{: >4}{GREEN}µs{RESET}", duration.subsec_micros())
+  } else if duration.as_millis() <= 99 {
+    format!(
+      "{: >4.1}{BRIGHT_BLACK}ms{RESET}",
+      (duration.subsec_micros() as f64) / 1_000.0
+    )
+  } else if duration.as_millis() <= 999 {
+    format!("{: >4}{BRIGHT_BLACK}ms{RESET}", duration.subsec_millis())
+  } else if duration.as_secs() <= 9 {
+    format!(
+      "{: >3.1}{RED}sec{RESET}",
+      (duration.as_millis() as f64) / 1_000.0
+    )
+  } else {
+    format!("{} {RED}seconds{RESET}", duration.as_secs())
+  }
+}
+
+fn bytes_string(bytes: u64) -> String {
+  const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+  let mut size = bytes as f64;
+  let mut unit = 0;
+  while size >= 1024.0 && unit < UNITS.len() - 1 {
+    size /= 1024.0;
+    unit += 1;
+  }
+  if unit == 0 {
+    format!("{bytes}B")
+  } else {
+    format!("{size:.1}{}", UNITS[unit])
+  }
+}
+
+fn memory_string(stats: &AllocStats) -> String {
+  format!(
+    " {BRIGHT_BLACK}·{RESET} {}{BRIGHT_BLACK} allocs, {RESET}{}{BRIGHT_BLACK}, peak {RESET}{}",
+    stats.allocations,
+    bytes_string(stats.bytes),
+    bytes_string(stats.peak)
+  )
+}
+
+/// How long parse and solve took for one run of one part,
+/// and what each allocated when `--memory` counts that.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Measurement {
+  pub parse: Duration,
+  /// Whether the other part's parse was reused, in which case parsing took no time for this one.
+  pub parse_reused: bool,
+  pub solve: Duration,
+  pub memory: Option<[AllocStats; 2]>,
+}
+
+/// One line of `--format json` output.
+#[derive(Serialize)]
+struct JsonRecord<'a> {
+  year: u16,
+  day: u16,
+  part: u8,
+  input: &'a str,
+  answer: Option<&'a str>,
+  expected: Option<&'a str>,
+  passed: Option<bool>,
+  /// Why the solver skipped this input, if it did.
+  skipped: Option<&'a str>,
+  /// The panic message and location, if the solver panicked on this input.
+  panicked: Option<&'a str>,
+  /// How long the solver ran before the runner gave up on it, if it did.
+  timed_out_ns: Option<u64>,
+  /// Whether it was never started, because the other part timed out on the same input.
+  abandoned: bool,
+  /// Whether the other part's parse of this input was reused, so `parse_ns` is 0.
+  parse_reused: bool,
+  parse_ns: u64,
+  solve_ns: u64,
+  /// What parse and solve allocated, with `--memory`.
+  parse_allocs: Option<u64>,
+  parse_bytes: Option<u64>,
+  parse_peak_bytes: Option<u64>,
+  solve_allocs: Option<u64>,
+  solve_bytes: Option<u64>,
+  solve_peak_bytes: Option<u64>,
+}
+
+/// Why an input got no answer.
+#[derive(Clone, Copy)]
+enum Unanswered<'a> {
+  Skipped(&'a str),
+  Panicked(&'a str),
+  TimedOut(Duration),
+  /// Never started, since the other part timed out on the same input.
+  Abandoned,
+}
+
+/// One line of `--format json --bench N` output.
+#[derive(Serialize)]
+struct JsonBenchRecord<'a> {
+  year: u16,
+  day: u16,
+  part: u8,
+  phase: &'a str,
+  runs: usize,
+  min_ns: u64,
+  median_ns: u64,
+  mean_ns: u64,
+  stddev_ns: u64,
+}
+
+/// Summary statistics over repeated timings of the same work.
+pub(crate) struct BenchStats {
+  runs: usize,
+  min: Duration,
+  median: Duration,
+  mean: Duration,
+  stddev: Duration,
+}
+
+impl BenchStats {
+  pub fn from_durations(mut durations: Vec<Duration>) -> BenchStats {
+    assert!(!durations.is_empty(), "Need at least one timing");
+    durations.sort();
+    let runs = durations.len();
+    let median = if runs.is_multiple_of(2) {
+      (durations[runs / 2 - 1] + durations[runs / 2]) / 2
+    } else {
+      durations[runs / 2]
+    };
+    let mean_ns = durations.iter().map(|d| d.as_nanos() as f64).sum::<f64>() / runs as f64;
+    let variance = if runs > 1 {
+      durations
+        .iter()
+        .map(|d| (d.as_nanos() as f64 - mean_ns).powi(2))
+        .sum::<f64>()
+        / (runs - 1) as f64
+    } else {
+      0.0
+    };
+    BenchStats {
+      runs,
+      min: durations[0],
+      median,
+      mean: Duration::from_nanos(mean_ns as u64),
+      stddev: Duration::from_nanos(variance.sqrt() as u64),
+    }
+  }
+}
+
+/// Renders the progress of `Day::run` in whichever format was requested.
+pub(crate) struct Reporter {
+  year: u16,
+  day: u16,
+  format: OutputFormat,
+  show_passing_samples: bool,
+  ignore_trailing_whitespace: bool,
+  summary: RefCell<DaySummary>,
+}
+
+impl Reporter {
+  pub fn new(
+    year: u16,
+    day: u16,
+    format: OutputFormat,
+    show_passing_samples: bool,
+    ignore_trailing_whitespace: bool,
+  ) -> Reporter {
+    Reporter {
+      year,
+      day,
+      format,
+      show_passing_samples,
+      ignore_trailing_whitespace,
+      summary: RefCell::new(DaySummary::default()),
+    }
+  }
+
+  fn same_answer(&self, a: &str, b: &str) -> bool {
+    answers::same_answer(a, b, self.ignore_trailing_whitespace)
+  }
+
+  /// The outcome of each part that was reported, whatever the format.
+  pub fn into_summary(self) -> DaySummary {
+    self.summary.into_inner()
+  }
+
+  fn record(&self, part: PartId, summary: PartSummary) {
+    self.summary.borrow_mut().parts[part.index()] = Some(summary);
+  }
+
+  fn aborted(&self, description: String) {
+    self.summary.borrow_mut().aborted.push(description);
+  }
+
+  fn json(
+    &self,
+    part: PartId,
+    input: &str,
+    answer: &str,
+    expected: Option<&str>,
+    measurement: Measurement,
+  ) {
+    let [parse_memory, solve_memory] = measurement.memory.map_or([None; 2], |m| m.map(Some));
+    let record = JsonRecord {
+      year: self.year,
+      day: self.day,
+      part: if part == PartId::P1 { 1 } else { 2 },
+      input,
+      answer: Some(answer),
+      expected,
+      passed: expected.map(|e| self.same_answer(e, answer)),
+      skipped: None,
+      panicked: None,
+      timed_out_ns: None,
+      abandoned: false,
+      parse_reused: measurement.parse_reused,
+      parse_ns: measurement.parse.as_nanos() as u64,
+      solve_ns: measurement.solve.as_nanos() as u64,
+      parse_allocs: parse_memory.map(|m| m.allocations),
+      parse_bytes: parse_memory.map(|m| m.bytes),
+      parse_peak_bytes: parse_memory.map(|m| m.peak),
+      solve_allocs: solve_memory.map(|m| m.allocations),
+      solve_bytes: solve_memory.map(|m| m.bytes),
+      solve_peak_bytes: solve_memory.map(|m| m.peak),
+    };
+    println!("{}", serde_json::to_string(&record).unwrap());
+  }
+
+  /// A record for an input that got no answer.
+  fn json_unanswered(&self, part: PartId, input: &str, why: Unanswered) {
+    let record = JsonRecord {
+      year: self.year,
+      day: self.day,
+      part: if part == PartId::P1 { 1 } else { 2 },
+      input,
+      answer: None,
+      expected: None,
+      passed: match why {
+        Unanswered::Skipped(_) => None,
+        Unanswered::Panicked(_) | Unanswered::TimedOut(_) | Unanswered::Abandoned => Some(false),
+      },
+      skipped: match why {
+        Unanswered::Skipped(reason) => Some(reason),
+        _ => None,
+      },
+      panicked: match why {
+        Unanswered::Panicked(message) => Some(message),
+        _ => None,
+      },
+      timed_out_ns: match why {
+        Unanswered::TimedOut(elapsed) => Some(elapsed.as_nanos() as u64),
+        _ => None,
+      },
+      abandoned: matches!(why, Unanswered::Abandoned),
+      parse_reused: false,
+      parse_ns: 0,
+      solve_ns: 0,
+      parse_allocs: None,
+      parse_bytes: None,
+      parse_peak_bytes: None,
+      solve_allocs: None,
+      solve_bytes: None,
+      solve_peak_bytes: None,
+    };
+    println!("{}", serde_json::to_string(&record).unwrap());
+  }
+
+  pub fn header(&self) {
+    let (year, day) = (self.year, self.day);
+    if self.format == OutputFormat::Pretty {
+      println!("🎄 {BOLD}Advent of Code {GREEN}{year}{RESET}, Day {BOLD}{GREEN}{day}{RESET} 🎄");
+    }
+  }
+
+  pub fn sample_passed(
+    &self,
+    part: PartId,
+    name: &str,
+    expected: &str,
+    answer: &str,
+    measurement: Measurement,
+  ) {
+    match self.format {
+      OutputFormat::Pretty if self.show_passing_samples => {
+        println!(" {GREEN}✓ {RESET}Part {part} test {YELLOW}{name}{RESET} {GREEN}passed{RESET}");
+      }
+      OutputFormat::Json => self.json(part, name, answer, Some(expected), measurement),
+      _ => {}
+    }
+  }
+
+  /// Shown even when passing samples aren't, since a skipped sample is a gap in what's tested.
+  pub fn sample_skipped(&self, part: PartId, name: &str, reason: &str) {
+    match self.format {
+      OutputFormat::Pretty => {
+        println!(
+          " {BRIGHT_BLACK}- {RESET}Part {part} test {YELLOW}{name}{RESET} {BRIGHT_BLACK}skipped: {reason}{RESET}"
+        );
+      }
+      OutputFormat::Json => self.json_unanswered(part, name, Unanswered::Skipped(reason)),
+      OutputFormat::Summary => {}
+    }
+  }
+
+  pub fn sample_timed_out(&self, part: PartId, name: &str, elapsed: Duration) {
+    self.aborted(format!(
+      "Part {part} test {name} timed out after {elapsed:.1?}"
+    ));
+    match self.format {
+      OutputFormat::Pretty => {
+        eprintln!(
+          " {RED}✕ {RESET}Part {part} test {YELLOW}{name}{RESET} {RED}timed out{RESET} after {}",
+          duration_string(elapsed)
+        );
+      }
+      OutputFormat::Json => self.json_unanswered(part, name, Unanswered::TimedOut(elapsed)),
+      OutputFormat::Summary => {}
+    }
+  }
+
+  /// The other part timed out on this sample, so this one was never started.
+  pub fn sample_abandoned(&self, part: PartId, name: &str) {
+    match self.format {
+      OutputFormat::Pretty => {
+        eprintln!(
+          " {RED}✕ {RESET}Part {part} test {YELLOW}{name}{RESET} {RED}not run{RESET}, since the other part timed out on it"
+        );
+      }
+      OutputFormat::Json => self.json_unanswered(part, name, Unanswered::Abandoned),
+      OutputFormat::Summary => {}
+    }
+  }
+
+  /// `shared_parse` is set when the panic was in a parse both parts share, which was already reported.
+  pub fn sample_panicked(&self, part: PartId, name: &str, message: &str, shared_parse: bool) {
+    if !shared_parse {
+      self.aborted(format!("Part {part} test {name} panicked: {message}"));
+    }
+    match self.format {
+      OutputFormat::Pretty if shared_parse => {
+        eprintln!(
+          " {RED}✕ {RESET}Part {part} test {YELLOW}{name}{RESET} {RED}panicked{RESET} while parsing, as shown above"
+        );
+      }
+      OutputFormat::Pretty => {
+        eprintln!(
+          " {RED}✕ {RESET}Part {part} test {YELLOW}{name}{RESET} {RED}panicked: {RESET}{message}"
+        );
+      }
+      OutputFormat::Json => self.json_unanswered(part, name, Unanswered::Panicked(message)),
+      OutputFormat::Summary => {}
+    }
+  }
+
+  pub fn sample_failed(
+    &self,
+    part: PartId,
+    name: &str,
+    expected: &str,
+    received: &str,
+    measurement: Measurement,
+  ) {
+    match self.format {
+      OutputFormat::Pretty => {
+        eprintln!(" {RED}✕ {RESET}Part {part} test {YELLOW}{name}{RESET} {RED}failed{RESET}");
+        let (expected, received) = (answers::normalize(expected), answers::normalize(received));
+        if expected.contains('\n') || received.contains('\n') {
+          for line in render_diff(expected, received, self.ignore_trailing_whitespace) {
+            eprintln!("{line}");
+          }
+        } else {
+          eprintln!(
+            "   {GREEN}Expected: {RESET}{expected}\n   {RED}Received: {RESET}{}",
+            highlight_changes(received, expected, BG_RED)
+          );
+        }
+      }
+      OutputFormat::Json => self.json(part, name, received, Some(expected), measurement),
+      OutputFormat::Summary => {}
+    }
+  }
+
+  pub fn star(&self, part: PartId, out: &str, known: Option<&str>, measurement: Measurement) {
+    let Measurement { parse, solve, .. } = measurement;
+    let regressed = known.is_some_and(|k| !self.same_answer(k, out));
+    if regressed {
+      self.aborted(format!("Part {part} regressed from the known-good answer"));
+    }
+    self.record(
+      part,
+      if regressed {
+        PartSummary::Regressed { parse, solve }
+      } else {
+        PartSummary::Passed { parse, solve }
+      },
+    );
+    match self.format {
+      OutputFormat::Pretty => {
+        let [parse_memory, solve_memory] = measurement
+          .memory
+          .map_or([String::new(), String::new()], |m| {
+            m.map(|s| memory_string(&s))
+          });
+        let parse_str = if measurement.parse_reused {
+          format!("{BRIGHT_BLACK}reused{RESET}")
+        } else {
+          format!("{}{parse_memory}", duration_string(parse))
+        };
+        let time_str = format!(
+          "{BRIGHT_BLACK}(Parse {RESET}{parse_str}{BRIGHT_BLACK}, Solve {RESET}{}{solve_memory}{BRIGHT_BLACK}){RESET}",
+          duration_string(solve)
+        );
+        let out = answers::normalize(out);
+        if out.contains('\n') {
+          // Multi-line answers are usually letters drawn in a grid, which only line up as a block.
+          println!(" {BOLD}{BRIGHT_YELLOW}★ {RESET}Star {part} {time_str}:");
+          for line in out.lines() {
+            println!("   {YELLOW}{line}{RESET}");
+          }
+        } else {
+          println!(" {BOLD}{BRIGHT_YELLOW}★ {RESET}Star {part} {time_str}: {YELLOW}{out}{RESET}");
+        }
+        if let Some(known) = known
+          && regressed
+        {
+          if known.contains('\n') || out.contains('\n') {
+            eprintln!(
+              " {RED}✕ {RESET}Part {part} {RED}regressed{RESET} from the known-good answer:"
+            );
+            for line in render_diff(known, out, self.ignore_trailing_whitespace) {
+              eprintln!("{line}");
+            }
+          } else {
+            eprintln!(
+              " {RED}✕ {RESET}Part {part} {RED}regressed{RESET}, the known-good answer is {YELLOW}{known}{RESET}"
+            );
+          }
+        }
+      }
+      OutputFormat::Json => self.json(part, "real", answers::normalize(out), known, measurement),
+      OutputFormat::Summary => {}
+    }
+  }
+
+  /// Warns that the answer about to be shown was already rejected when it was submitted.
+  pub fn ruled_out(&self, part: PartId, earlier: &LedgerEntry) {
+    if self.format == OutputFormat::Pretty {
+      eprintln!(
+        " {YELLOW}⚠ {RESET}Part {part} answer is {RED}already ruled out{RESET} by the earlier submission {YELLOW}{earlier}{RESET}"
+      );
+    }
+  }
+
+  pub fn accepted(&self, part: PartId) {
+    if self.format == OutputFormat::Pretty {
+      println!(" {GREEN}✓ {RESET}Recorded star {part} as the known-good answer");
+    }
+  }
+
+  pub fn star_skipped(&self, part: PartId, reason: &str) {
+    self.record(
+      part,
+      PartSummary::Skipped {
+        reason: reason.to_string(),
+      },
+    );
+    match self.format {
+      OutputFormat::Pretty => {
+        println!(" {BRIGHT_BLACK}☆ {RESET}Star {part} {BRIGHT_BLACK}skipped: {reason}{RESET}");
+      }
+      OutputFormat::Json => self.json_unanswered(part, "real", Unanswered::Skipped(reason)),
+      OutputFormat::Summary => {}
+    }
+  }
+
+  /// `shared_parse` is set when the panic was in a parse both parts share, which was already reported.
+  pub fn star_panicked(&self, part: PartId, message: &str, shared_parse: bool) {
+    self.record(part, PartSummary::Crashed);
+    if !shared_parse {
+      self.aborted(format!("Part {part} panicked: {message}"));
+    }
+    match self.format {
+      OutputFormat::Pretty if shared_parse => {
+        eprintln!(" {RED}★ {RESET}Star {part}: {RED}panicked{RESET} while parsing, as shown above");
+      }
+      OutputFormat::Pretty => {
+        eprintln!(" {RED}★ {RESET}Star {part}: {RED}panicked: {RESET}{message}");
+      }
+      OutputFormat::Json => self.json_unanswered(part, "real", Unanswered::Panicked(message)),
+      OutputFormat::Summary => {}
+    }
+  }
+
+  pub fn star_timed_out(&self, part: PartId, elapsed: Duration) {
+    self.record(part, PartSummary::TimedOut { elapsed });
+    self.aborted(format!("Part {part} timed out after {elapsed:.1?}"));
+    match self.format {
+      OutputFormat::Pretty => {
+        eprintln!(
+          " {RED}★ {RESET}Star {part}: {RED}timed out{RESET} after {}",
+          duration_string(elapsed)
+        );
+      }
+      OutputFormat::Json => self.json_unanswered(part, "real", Unanswered::TimedOut(elapsed)),
+      OutputFormat::Summary => {}
+    }
+  }
+
+  /// Only recorded for the summary, since a run only shows the parts it was asked for.
+  pub fn not_run(&self, part: PartId) {
+    self.record(part, PartSummary::NotRun);
+  }
+
+  /// The other part timed out on the real input (or `--input`), so this one was never started.
+  pub fn star_abandoned(&self, part: PartId) {
+    self.record(part, PartSummary::NotRun);
+    match self.format {
+      OutputFormat::Pretty => {
+        eprintln!(
+          " {BRIGHT_BLACK}☆ {RESET}Star {part} {BRIGHT_BLACK}not run, since the other part timed out on this input{RESET}"
+        );
+      }
+      OutputFormat::Json => self.json_unanswered(part, "real", Unanswered::Abandoned),
+      OutputFormat::Summary => {}
+    }
+  }
+
+  /// Only recorded for the summary, since `--test` already showed each sample passing.
+  pub fn samples_passed(&self, part: PartId) {
+    self.record(part, PartSummary::SamplesPassed);
+  }
+
+  pub fn star_failed(&self, part: PartId, failures: usize) {
+    self.record(part, PartSummary::Failed { failures });
+    if self.format == OutputFormat::Pretty {
+      eprintln!(" {RED}★ {RESET}Star {part}: {RED}{failures:?} failed test(s){RESET}");
+    }
+  }
+
+  pub fn bench(&self, part: PartId, parse: &BenchStats, solve: &BenchStats) {
+    for (phase, stats) in [("Parse", parse), ("Solve", solve)] {
+      match self.format {
+        OutputFormat::Pretty => println!(
+          " {BOLD}{BRIGHT_YELLOW}⏱ {RESET}Part {part} {phase} {BRIGHT_BLACK}min {RESET}{} {BRIGHT_BLACK}median {RESET}{} {BRIGHT_BLACK}mean {RESET}{} {BRIGHT_BLACK}±{RESET}{} {BRIGHT_BLACK}({} runs){RESET}",
+          duration_string(stats.min),
+          duration_string(stats.median),
+          duration_string(stats.mean),
+          duration_string(stats.stddev),
+          stats.runs
+        ),
+        OutputFormat::Json => {
+          let record = JsonBenchRecord {
+            year: self.year,
+            day: self.day,
+            part: if part == PartId::P1 { 1 } else { 2 },
+            phase: if phase == "Parse" { "parse" } else { "solve" },
+            runs: stats.runs,
+            min_ns: stats.min.as_nanos() as u64,
+            median_ns: stats.median.as_nanos() as u64,
+            mean_ns: stats.mean.as_nanos() as u64,
+            stddev_ns: stats.stddev.as_nanos() as u64,
+          };
+          println!("{}", serde_json::to_string(&record).unwrap());
+        }
+        OutputFormat::Summary => {}
+      }
+    }
+  }
+
+  pub fn total(&self, elapsed: Duration) {
+    if self.format == OutputFormat::Pretty {
+      println!(
+        "⌛{BOLD}{BRIGHT_BLACK} Total:{RESET}{}",
+        duration_string(elapsed)
+      );
+    }
+  }
+}
+
+/// The outcome of one part of one day, for summary tables.
+#[derive(Clone, Debug)]
+pub enum PartSummary {
+  Passed {
+    parse: Duration,
+    solve: Duration,
+  },
+  /// The answer for the real input no longer matches the known-good one.
+  Regressed {
+    parse: Duration,
+    solve: Duration,
+  },
+  Failed {
+    failures: usize,
+  },
+  /// The solver says this part doesn't apply to the real input, like part 2 of day 25.
+  Skipped {
+    reason: String,
+  },
+  /// The solver panicked on the real input.
+  Crashed,
+  /// The runner gave up on the solver after it ran this long on the real input.
+  TimedOut {
+    elapsed: Duration,
+  },
+  /// Every sample passed, and the real input wasn't run because of `--test`.
+  SamplesPassed,
+  /// Only the other part was asked for.
+  NotRun,
+}
+
+const SUMMARY_PREFIX: &str = "summary";
+
+impl PartSummary {
+  fn to_line(&self, part: PartId) -> String {
+    match self {
+      PartSummary::Passed { parse, solve } => format!(
+        "{SUMMARY_PREFIX}\t{part}\tpass\t{}\t{}",
+        parse.as_nanos(),
+        solve.as_nanos()
+      ),
+      PartSummary::Regressed { parse, solve } => format!(
+        "{SUMMARY_PREFIX}\t{part}\tregress\t{}\t{}",
+        parse.as_nanos(),
+        solve.as_nanos()
+      ),
+      PartSummary::Failed { failures } => format!("{SUMMARY_PREFIX}\t{part}\tfail\t{failures}"),
+      PartSummary::Skipped { reason } => format!(
+        "{SUMMARY_PREFIX}\t{part}\tskip\t{}",
+        reason.replace(['\t', '\n'], " ")
+      ),
+      PartSummary::Crashed => format!("{SUMMARY_PREFIX}\t{part}\tcrash"),
+      PartSummary::TimedOut { elapsed } => {
+        format!("{SUMMARY_PREFIX}\t{part}\ttimeout\t{}", elapsed.as_nanos())
+      }
+      PartSummary::SamplesPassed => format!("{SUMMARY_PREFIX}\t{part}\ttested"),
+      PartSummary::NotRun => format!("{SUMMARY_PREFIX}\t{part}\tnotrun"),
+    }
+  }
+
+  /// Reads a line written by `to_line`.
+  fn from_line(line: &str) -> Option<(PartId, PartSummary)> {
+    let fields: Vec<&str> = line.split('\t').collect();
+    if fields.len() < 3 || fields[0] != SUMMARY_PREFIX {
+      return None;
+    }
+    let part = match fields[1] {
+      "1" => PartId::P1,
+      "2" => PartId::P2,
+      _ => return None,
+    };
+    match (fields[2], fields.len()) {
+      ("pass", 5) => Some((
+        part,
+        PartSummary::Passed {
+          parse: Duration::from_nanos(fields[3].parse().ok()?),
+          solve: Duration::from_nanos(fields[4].parse().ok()?),
+        },
+      )),
+      ("regress", 5) => Some((
+        part,
+        PartSummary::Regressed {
+          parse: Duration::from_nanos(fields[3].parse().ok()?),
+          solve: Duration::from_nanos(fields[4].parse().ok()?),
+        },
+      )),
+      ("fail", 4) => Some((
+        part,
+        PartSummary::Failed {
+          failures: fields[3].parse().ok()?,
+        },
+      )),
+      ("skip", 4) => Some((
+        part,
+        PartSummary::Skipped {
+          reason: fields[3].to_string(),
+        },
+      )),
+      ("crash", 3) => Some((part, PartSummary::Crashed)),
+      ("tested", 3) => Some((part, PartSummary::SamplesPassed)),
+      ("notrun", 3) => Some((part, PartSummary::NotRun)),
+      ("timeout", 4) => Some((
+        part,
+        PartSummary::TimedOut {
+          elapsed: Duration::from_nanos(fields[3].parse().ok()?),
+        },
+      )),
+      _ => None,
+    }
+  }
+}
+
+/// The outcome of each part of one day that got far enough to have one.
+#[derive(Clone, Debug, Default)]
+pub struct DaySummary {
+  pub parts: [Option<PartSummary>; 2],
+  /// Each time the solver panicked, timed out, or regressed. The run carries on past those, but still fails at the end.
+  /// Not part of `to_lines`, since a child process reports these as its error instead.
+  pub aborted: Vec<String>,
+}
+
+impl DaySummary {
+  pub fn passed(&self) -> bool {
+    self.parts.iter().all(|p| {
+      matches!(
+        p,
+        Some(
+          PartSummary::Passed { .. }
+            | PartSummary::Skipped { .. }
+            | PartSummary::SamplesPassed
+            | PartSummary::NotRun
+        )
+      )
+    })
+  }
+
+  pub fn total(&self) -> Duration {
+    self
+      .parts
+      .iter()
+      .map(|p| match p {
+        Some(PartSummary::Passed { parse, solve } | PartSummary::Regressed { parse, solve }) => {
+          *parse + *solve
+        }
+        _ => Duration::ZERO,
+      })
+      .sum()
+  }
+
+  /// How a child process passes its summary to `exec_year` over stdout.
+  pub(crate) fn to_lines(&self) -> Vec<String> {
+    [PartId::P1, PartId::P2]
+      .iter()
+      .filter_map(|&part| Some(self.parts[part.index()].as_ref()?.to_line(part)))
+      .collect()
+  }
+
+  /// Reads the output of `to_lines`. Solvers can print whatever they like
+  /// to stdout too, so any line that isn't a summary is ignored.
+  pub(crate) fn from_lines(stdout: &str) -> DaySummary {
+    let mut summary = DaySummary::default();
+    for (part, part_summary) in stdout.lines().filter_map(PartSummary::from_line) {
+      summary.parts[part.index()] = Some(part_summary);
+    }
+    summary
+  }
+}
+
+/// One row of the table printed by `print_year_table`.
+pub(crate) struct DayRow {
+  pub day: u16,
+  pub summary: DaySummary,
+  /// Set if the day stopped early, because of an error or a crash.
+  pub error: Option<String>,
+}
+
+impl DayRow {
+  pub fn passed(&self) -> bool {
+    self.error.is_none() && self.summary.passed()
+  }
+}
+
+fn part_cell(part: &Option<PartSummary>, stopped_early: bool) -> String {
+  match part {
+    Some(PartSummary::Passed { parse, solve }) => format!(
+      "{GREEN}✓{RESET} {} {}",
+      duration_string(*parse),
+      duration_string(*solve)
+    ),
+    Some(PartSummary::Regressed { parse, solve }) => format!(
+      "{RED}✕{RESET} {} {}",
+      duration_string(*parse),
+      duration_string(*solve)
+    ),
+    Some(PartSummary::Failed { failures }) => format!("{RED}✕ {failures: >2} failed{RESET}    "),
+    Some(PartSummary::Skipped { .. }) => format!("{BRIGHT_BLACK}-   skipped{RESET}    "),
+    Some(PartSummary::SamplesPassed) => {
+      format!("{GREEN}✓{RESET}   {BRIGHT_BLACK}samples{RESET}    ")
+    }
+    Some(PartSummary::NotRun) => format!("{BRIGHT_BLACK}-   not run{RESET}    "),
+    None if !stopped_early => format!("{BRIGHT_BLACK}-   not run{RESET}    "),
+    Some(PartSummary::Crashed) | None => format!("{RED}✕   crashed{RESET}    "),
+    Some(PartSummary::TimedOut { .. }) => format!("{RED}✕   timed out{RESET}  "),
+  }
+}
+
+/// Prints the results of running every day in a year, one row per day.
+pub(crate) fn print_year_table(year: u16, days: &[DayRow]) {
+  println!("🎄 {BOLD}Advent of Code {GREEN}{year}{RESET} 🎄");
+  println!("{BRIGHT_BLACK}     │ Part 1          │ Part 2          │{RESET}");
+  println!("{BRIGHT_BLACK} Day │    Parse  Solve │    Parse  Solve │  Total{RESET}");
+  for row in days {
+    let day = row.day;
+    println!(
+      "  {BOLD}{day:0>2}{RESET} {BRIGHT_BLACK}│{RESET} {} {BRIGHT_BLACK}│{RESET} {} {BRIGHT_BLACK}│{RESET} {}",
+      part_cell(&row.summary.parts[0], row.error.is_some()),
+      part_cell(&row.summary.parts[1], row.error.is_some()),
+      duration_string(row.summary.total())
+    );
+    if let Some(error) = &row.error {
+      // Show the start of the error, not the backtrace after it.
+      for line in error
+        .lines()
+        .take_while(|l| !l.starts_with("Stack backtrace"))
+        .filter(|l| !l.trim().is_empty())
+        .take(3)
+      {
+        println!("     {BRIGHT_BLACK}│ {RED}{line}{RESET}");
+      }
+    }
+  }
+  let passed = days.iter().filter(|d| d.passed()).count();
+  let total: Duration = days.iter().map(|d| d.summary.total()).sum();
+  let color = if passed == days.len() { GREEN } else { RED };
+  println!(
+    "⌛{BOLD}{BRIGHT_BLACK} Total:{RESET}{} {BRIGHT_BLACK}({color}{passed}{BRIGHT_BLACK}/{} days passed){RESET}",
+    duration_string(total),
+    days.len()
+  );
+}
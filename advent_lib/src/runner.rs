@@ -1,15 +1,207 @@
+use crate::answers;
 use crate::color::*;
+use crate::history;
+use crate::ledger::Ledger;
+use crate::memory;
+use crate::params;
+pub use crate::params::Params;
+use crate::registry::{RegisteredDay, YearRegistry};
+use crate::report::{BenchStats, DayRow, Measurement, Reporter, print_year_table};
 use anyhow::{Context, Result, bail};
 use cargo_metadata::MetadataCommand;
+use clap::{Args, Parser, ValueEnum};
 use regex::Regex;
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::env;
+use std::fmt;
 use std::fs;
+use std::hint::black_box;
+use std::io::{self, Read};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Once, OnceLock};
+use std::thread;
 use std::time::{Duration, Instant};
 use std::{path::PathBuf, process::Command};
 
+pub use crate::report::{DaySummary, PartSummary};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+  /// Colorful, human-readable results
+  #[default]
+  Pretty,
+  /// One JSON object per line, for each part of each input
+  Json,
+  /// Nothing while running, then one tab-separated line per part, for `exec_year` to read
+  #[value(hide = true)]
+  Summary,
+}
+
+/// Flags that change what `Day::run` does.
+/// The main binary accepts these, and `exec_day` forwards them to the child process.
+#[derive(Args, Clone, Debug, Default)]
+pub struct RunOptions {
+  /// Only run the samples, report every result, and never touch the real input
+  #[arg(long)]
+  pub test: bool,
+
+  /// How to print results
+  #[arg(long, value_enum, default_value_t)]
+  pub format: OutputFormat,
+
+  /// Record this run's answers for the real input as the known-good ones
+  #[arg(long, conflicts_with = "test")]
+  pub accept: bool,
+
+  /// Skip the samples, and time parse and both parts N times each on the real input
+  #[arg(long, value_name = "N", conflicts_with = "test")]
+  pub bench: Option<usize>,
+
+  /// Only run this part
+  #[arg(long, value_enum)]
+  pub part: Option<PartId>,
+
+  /// Only run the samples with this name, or matching this glob, like `test0*`. Can be repeated
+  #[arg(long, value_name = "NAME")]
+  pub sample: Vec<String>,
+
+  /// Skip the samples and only run the real input
+  #[arg(long, conflicts_with_all = ["test", "sample"])]
+  pub real_only: bool,
+
+  /// Run this file instead of the samples and real input, or `-` to read standard input
+  #[arg(long, value_name = "PATH", conflicts_with_all = ["sample", "real_only", "accept"])]
+  pub input: Option<PathBuf>,
+
+  /// The answer `--input` should give for `--part`, to check it like a sample
+  #[arg(long, value_name = "ANSWER", requires_all = ["input", "part"], allow_hyphen_values = true)]
+  pub expect: Option<String>,
+
+  /// Ignore whitespace at the end of each line when comparing answers
+  #[arg(long)]
+  pub ignore_trailing_whitespace: bool,
+
+  /// Give up on parsing and solving a part for one input after this many seconds, instead of the day's own limit
+  #[arg(long, value_name = "SECONDS", value_parser = parse_seconds)]
+  pub timeout: Option<Duration>,
+
+  /// Count the allocations, bytes allocated, and peak memory of each parse and solve
+  #[arg(long)]
+  pub memory: bool,
+}
+
+fn parse_seconds(arg: &str) -> Result<Duration> {
+  let seconds: f64 = arg.parse().context("Expected a number of seconds")?;
+  Duration::try_from_secs_f64(seconds).context("Expected a positive number of seconds")
+}
+
+impl RunOptions {
+  /// Reads the options that `exec_day` passed to this process.
+  pub fn from_env() -> RunOptions {
+    ChildArgs::parse().options
+  }
+
+  fn to_args(&self) -> Vec<String> {
+    let mut args = vec![];
+    if self.test {
+      args.push("--test".to_string());
+    }
+    if self.format != OutputFormat::default() {
+      args.push("--format".to_string());
+      args.push(
+        self
+          .format
+          .to_possible_value()
+          .unwrap()
+          .get_name()
+          .to_string(),
+      );
+    }
+    if self.accept {
+      args.push("--accept".to_string());
+    }
+    if let Some(iterations) = self.bench {
+      args.push("--bench".to_string());
+      args.push(iterations.to_string());
+    }
+    if let Some(part) = self.part {
+      args.push("--part".to_string());
+      args.push(part.to_string());
+    }
+    for sample in self.sample.iter() {
+      args.push("--sample".to_string());
+      args.push(sample.clone());
+    }
+    if self.real_only {
+      args.push("--real-only".to_string());
+    }
+    if let Some(input) = &self.input {
+      // The child runs from the workspace root, so a relative path would point somewhere else.
+      let input = if input == Path::new("-") {
+        input.clone()
+      } else {
+        std::path::absolute(input).unwrap_or(input.clone())
+      };
+      args.push("--input".to_string());
+      args.push(input.to_string_lossy().to_string());
+    }
+    if let Some(expect) = &self.expect {
+      args.push(format!("--expect={expect}"));
+    }
+    if self.ignore_trailing_whitespace {
+      args.push("--ignore-trailing-whitespace".to_string());
+    }
+    if let Some(timeout) = self.timeout {
+      args.push("--timeout".to_string());
+      args.push(timeout.as_secs_f64().to_string());
+    }
+    if self.memory {
+      args.push("--memory".to_string());
+    }
+    args
+  }
+
+  /// Extra arguments for building the day's binary with these options.
+  fn cargo_args(&self) -> Vec<&'static str> {
+    if self.memory {
+      vec!["--features", "advent_lib/count-allocations"]
+    } else {
+      vec![]
+    }
+  }
+
+  pub fn runs_part(&self, part: PartId) -> bool {
+    self.part.is_none_or(|p| p == part)
+  }
+
+  /// Whether `--sample` and `--real-only` let this sample run. `*` and `?` work like in a shell.
+  pub fn runs_sample(&self, name: &str) -> bool {
+    if self.real_only {
+      return false;
+    }
+    self.sample.is_empty()
+      || self.sample.iter().any(|pattern| {
+        let pattern = regex::escape(pattern)
+          .replace(r"\*", ".*")
+          .replace(r"\?", ".");
+        Regex::new(&format!("^{pattern}$")).is_ok_and(|re| re.is_match(name))
+      })
+  }
+}
+
+#[derive(Parser, Debug)]
+struct ChildArgs {
+  #[command(flatten)]
+  options: RunOptions,
+}
+
 /// Solutions for a given day call this function to organize their solvers.
 /// The solution to use is executed as a child process from that year's bin.
 /// That child process is also written in Rust, and imports this library too.
-pub fn exec_day(year: u16, day: u16, workspace_root: &PathBuf) -> Result<()> {
+pub fn exec_day(year: u16, day: u16, workspace_root: &PathBuf, options: &RunOptions) -> Result<()> {
   let exit_status = Command::new("cargo")
     .current_dir(workspace_root)
     .arg("-q")
@@ -19,6 +211,9 @@ pub fn exec_day(year: u16, day: u16, workspace_root: &PathBuf) -> Result<()> {
     .arg(format!("advent_of_code_{year}"))
     .arg("--bin")
     .arg(format!("day{day:0>2}"))
+    .args(options.cargo_args())
+    .arg("--")
+    .args(options.to_args())
     .spawn()
     .with_context(|| {
       format!("Failed to execute `cargo run --package advent_of_code_{year} --bin day{day:0>2}`")
@@ -32,31 +227,616 @@ pub fn exec_day(year: u16, day: u16, workspace_root: &PathBuf) -> Result<()> {
   Ok(())
 }
 
-#[derive(PartialEq)]
+/// Runs every day in a year and prints a summary table. Returns whether every day passed.
+///
+/// If the year is in the registry, this binary already has its days compiled in, and runs them in this process,
+/// except for days that declare their own `TIME_LIMIT`, which each run as a child process of this binary.
+/// Otherwise, the year's package is built once and each day's binary is run in turn.
+pub fn exec_year(
+  year: u16,
+  workspace_root: &Path,
+  options: &RunOptions,
+  registry: Option<&YearRegistry>,
+) -> Result<bool> {
+  let child_options = RunOptions {
+    format: OutputFormat::Summary,
+    ..options.clone()
+  };
+
+  let rows = match registry {
+    Some(registry) => run_year_registered(registry, workspace_root, &child_options)?,
+    None => run_year_binaries(year, workspace_root, &child_options)?,
+  };
+
+  print_year_table(year, &rows);
+  Ok(rows.iter().all(|r| r.passed()))
+}
+
+/// Runs one day's process to the end, and reads its summary from what it printed.
+fn run_day_process(day: u16, command: &mut Command) -> Result<DayRow> {
+  let output = command
+    .output()
+    .with_context(|| format!("Failed to run day {day:0>2}"))?;
+  Ok(DayRow {
+    day,
+    summary: DaySummary::from_lines(&String::from_utf8_lossy(&output.stdout)),
+    error: if output.status.success() {
+      None
+    } else {
+      Some(String::from_utf8_lossy(&output.stderr).to_string())
+    },
+  })
+}
+
+/// Runs each day in this process, or with `{this binary} run-day YEAR DAY` if it wants its own process,
+/// which the main binary handles with `run_registered_day`.
+fn run_year_registered(
+  registry: &YearRegistry,
+  workspace_root: &Path,
+  options: &RunOptions,
+) -> Result<Vec<DayRow>> {
+  let exe = env::current_exe().context("Could not find this binary to run days with")?;
+  registry
+    .days
+    .iter()
+    .map(|registered| {
+      if !registered.own_process {
+        return Ok(run_day_in_process(registered, options));
+      }
+      run_day_process(
+        registered.day,
+        Command::new(&exe)
+          .current_dir(workspace_root)
+          .arg(RUN_DAY_COMMAND)
+          .arg(registry.year.to_string())
+          .arg(registered.day.to_string())
+          .args(options.to_args()),
+      )
+    })
+    .collect()
+}
+
+fn run_day_in_process(registered: &RegisteredDay, options: &RunOptions) -> DayRow {
+  // Keep going if one day panics, just like a crashed child process wouldn't stop the rest.
+  let (summary, error) = match panic::catch_unwind(|| (registered.run)(options)) {
+    Ok(Ok(summary)) if !summary.aborted.is_empty() => {
+      let error = summary.aborted.join("\n");
+      (summary, Some(error))
+    }
+    Ok(Ok(summary)) => (summary, None),
+    Ok(Err(err)) => (DaySummary::default(), Some(format!("{err:?}"))),
+    Err(payload) => (DaySummary::default(), Some(panic_message(&payload))),
+  };
+  DayRow {
+    day: registered.day,
+    summary,
+    error,
+  }
+}
+
+fn run_year_binaries(
+  year: u16,
+  workspace_root: &Path,
+  options: &RunOptions,
+) -> Result<Vec<DayRow>> {
+  let package = format!("advent_of_code_{year}");
+  let build_status = Command::new("cargo")
+    .current_dir(workspace_root)
+    .arg("-q")
+    .arg("build")
+    .arg("--release")
+    .arg("--package")
+    .arg(&package)
+    .args(options.cargo_args())
+    .status()
+    .with_context(|| format!("Failed to execute `cargo build --package {package}`"))?;
+  if !build_status.success() {
+    bail!("Failed to build {package}");
+  }
+
+  let metadata = MetadataCommand::new()
+    .current_dir(workspace_root)
+    .no_deps()
+    .exec()
+    .context("Could not read Cargo metadata")?;
+  let bin_dir = metadata.target_directory.join("release");
+  let mut days: Vec<u16> = metadata
+    .packages
+    .iter()
+    .filter(|p| p.name == package)
+    .flat_map(|p| p.targets.iter())
+    .filter(|t| t.is_bin())
+    .filter_map(|t| t.name.strip_prefix("day")?.parse().ok())
+    .collect();
+  days.sort();
+
+  days
+    .into_iter()
+    .map(|day| {
+      run_day_process(
+        day,
+        Command::new(bin_dir.join(format!("day{day:0>2}")))
+          .current_dir(workspace_root)
+          .args(options.to_args()),
+      )
+    })
+    .collect()
+}
+
+/// Runs `exec_year` for every year in the workspace, in order.
+/// Returns whether every day of every year passed.
+pub fn exec_all_years(
+  workspace_root: &Path,
+  options: &RunOptions,
+  registries: &[YearRegistry],
+) -> Result<bool> {
+  let metadata = MetadataCommand::new()
+    .current_dir(workspace_root)
+    .no_deps()
+    .exec()
+    .context("Could not read Cargo metadata")?;
+  let mut years: Vec<u16> = metadata
+    .packages
+    .iter()
+    .filter_map(|p| p.name.strip_prefix("advent_of_code_")?.parse().ok())
+    .collect();
+  years.sort();
+
+  let mut all_passed = true;
+  for year in years {
+    let registry = registries.iter().find(|r| r.year == year);
+    all_passed &= exec_year(year, workspace_root, options, registry)?;
+    println!();
+  }
+  Ok(all_passed)
+}
+
+/// Everything a solution's `main` needs to do: read the options `exec_day`
+/// or `exec_year` passed in, run, and hand the summary back if asked for one.
+pub fn run_from_command_line(run: impl FnOnce(&RunOptions) -> Result<DaySummary>) -> Result<()> {
+  run_and_summarize(&RunOptions::from_env(), run)
+}
+
+/// The subcommand of the main binary that `exec_year` runs a registered day in its own process with.
+pub const RUN_DAY_COMMAND: &str = "run-day";
+
+/// What the main binary does for `run-day YEAR DAY`: the same as the day's own binary would.
+pub fn run_registered_day(registry: &YearRegistry, day: u16, options: &RunOptions) -> Result<()> {
+  let run = registry
+    .get(day)
+    .with_context(|| format!("Day {day} of {} isn't in the registry", registry.year))?;
+  run_and_summarize(options, run)
+}
+
+fn run_and_summarize(
+  options: &RunOptions,
+  run: impl FnOnce(&RunOptions) -> Result<DaySummary>,
+) -> Result<()> {
+  let summary = run(options)?;
+  if options.format == OutputFormat::Summary {
+    for line in summary.to_lines() {
+      println!("{line}");
+    }
+  }
+  // Everything else still ran, but a panic should never look like success.
+  if !summary.aborted.is_empty() {
+    bail!("{}", summary.aborted.join("\n"));
+  }
+  Ok(())
+}
+
+/// Returned by a solver for an input it doesn't apply to, like a sample that's a different kind of program.
+/// The runner reports it as skipped, rather than passed or failed.
+#[derive(Debug)]
+pub struct Skipped(pub String);
+
+impl fmt::Display for Skipped {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "Skipped: {}", self.0)
+  }
+}
+
+impl std::error::Error for Skipped {}
+
+/// Ends `parse`, `part1` or `part2` early with a `Skipped` outcome.
+/// Skipping in `parse` skips both parts for that input.
+pub fn skip<T>(reason: impl Into<String>) -> Result<T> {
+  Err(Skipped(reason.into()).into())
+}
+
+enum Outcome<T> {
+  Done(T),
+  Skipped(String),
+  /// The panic message, and where it happened.
+  Panicked(String),
+  /// Parsing panicked for the other part too, and the message was shown then, since the parse is shared.
+  AlreadyPanicked(String),
+  /// How long it ran before the runner gave up on it.
+  TimedOut(Duration),
+  /// The other part timed out on this input, and its worker is still busy with it.
+  AlreadyTimedOut,
+}
+
+impl<T> Outcome<T> {
+  fn map<U>(self, f: impl FnOnce(T) -> U) -> Outcome<U> {
+    match self {
+      Outcome::Done(value) => Outcome::Done(f(value)),
+      Outcome::Skipped(reason) => Outcome::Skipped(reason),
+      Outcome::Panicked(message) => Outcome::Panicked(message),
+      Outcome::AlreadyPanicked(message) => Outcome::AlreadyPanicked(message),
+      Outcome::TimedOut(elapsed) => Outcome::TimedOut(elapsed),
+      Outcome::AlreadyTimedOut => Outcome::AlreadyTimedOut,
+    }
+  }
+}
+
+thread_local! {
+  static CATCHING_PANICS: Cell<bool> = const { Cell::new(false) };
+  static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Runs one `parse`, `part1` or `part2` call, so that a skip or a panic only ends that call.
+/// Only real errors get passed along with `?`.
+fn attempt<T>(f: impl FnOnce() -> Result<T>) -> Result<Outcome<T>> {
+  // While a call is being attempted, the panic hook only notes where the panic happened,
+  // and the runner reports it instead of the default hook printing it.
+  static HOOK: Once = Once::new();
+  HOOK.call_once(|| {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+      if CATCHING_PANICS.with(Cell::get) {
+        let location = info.location().map(|l| l.to_string());
+        PANIC_LOCATION.with(|p| p.replace(location));
+      } else {
+        default_hook(info);
+      }
+    }));
+  });
+
+  let was_catching = CATCHING_PANICS.with(|c| c.replace(true));
+  let result = panic::catch_unwind(AssertUnwindSafe(f));
+  CATCHING_PANICS.with(|c| c.set(was_catching));
+
+  match result {
+    Ok(Ok(value)) => Ok(Outcome::Done(value)),
+    Ok(Err(err)) => match err.downcast::<Skipped>() {
+      Ok(Skipped(reason)) => Ok(Outcome::Skipped(reason)),
+      Err(err) => Err(err),
+    },
+    Err(payload) => {
+      let message = panic_message(&payload);
+      Ok(Outcome::Panicked(match PANIC_LOCATION.with(|p| p.take()) {
+        Some(location) => format!("{message} (at {location})"),
+        None => message,
+      }))
+    }
+  }
+}
+
+/// Runs `f`, and returns how long it took and what it allocated on this thread.
+fn measured<T>(f: impl FnOnce() -> T) -> (T, Duration, memory::AllocStats) {
+  let start = Instant::now();
+  let (result, allocated) = memory::measure(f);
+  (result, start.elapsed(), allocated)
+}
+
+/// More than the main thread gets, so solvers that recurse deeply still fit on a worker thread.
+const WORKER_STACK_SIZE: usize = 256 * 1024 * 1024;
+
+/// A worker thread for one input, which parses it and solves whichever parts it's asked to, so the runner
+/// can give up on a part once it has taken its time limit. When the day's `PARSE_ONCE` is set,
+/// the first parse is kept and shared by both parts, and so is the way it failed, if it did.
+struct InputWorker {
+  parts: Sender<PartId>,
+  results: Receiver<Result<Outcome<(String, Measurement)>>>,
+  timed_out: bool,
+}
+
+impl InputWorker {
+  /// Starts the thread. Only the answers come back, along with how long parse and solve took,
+  /// and what they allocated if `count_allocations` is set.
+  fn start<D, Parsed, P1, P2>(
+    solver: &'static D,
+    sample_name: Option<&str>,
+    lines: Vec<String>,
+    params: &Params,
+    count_allocations: bool,
+  ) -> Result<InputWorker>
+  where
+    D: Day<Parsed, P1, P2> + Sync + ?Sized,
+    P1: fmt::Debug + fmt::Display,
+    P2: fmt::Debug + fmt::Display,
+  {
+    let sample_name = sample_name.map(str::to_string);
+    let params = params.clone();
+    let input = match &sample_name {
+      Some(name) => format!("sample {YELLOW}{name}{RESET}"),
+      None => "real data".to_string(),
+    };
+    let thread_name = match &sample_name {
+      Some(name) => format!("sample {name}"),
+      None => "real input".to_string(),
+    };
+    let (parts, requests) = mpsc::channel::<PartId>();
+    let (sender, results) = mpsc::channel();
+    // The shared parse stays on the worker thread, so `Parsed` doesn't have to be `Send`.
+    let solve = move |part: PartId,
+                      shared: &mut Option<Outcome<Parsed>>|
+          -> Result<Outcome<(String, Measurement)>> {
+      let parse_reused = shared.is_some();
+      let (parsed, parse_duration, parse_memory) = match shared.take() {
+        Some(parsed) => (parsed, Duration::ZERO, memory::AllocStats::default()),
+        None => match attempt(|| {
+          // Copying the input is not part of what's being measured.
+          let (lines, name) = (lines.clone(), sample_name.clone());
+          let (parsed, duration, memory) = measured(|| solver.parse(lines, name, part, &params));
+          parsed.map(|parsed| (parsed, duration, memory))
+        })
+        .with_context(|| format!(" {RED}✕ {RESET}Parse error on {input}"))?
+        {
+          Outcome::Done((parsed, duration, memory)) => (Outcome::Done(parsed), duration, memory),
+          failed => (
+            failed.map(|(parsed, ..)| parsed),
+            Duration::ZERO,
+            Default::default(),
+          ),
+        },
+      };
+      let result = match &parsed {
+        Outcome::Done(parsed) => attempt(|| {
+          let name = sample_name.clone();
+          let (out, solve_duration, solve_memory) = match part {
+            PartId::P1 => {
+              let (out, duration, memory) = measured(|| solver.part1(parsed, name));
+              (out?.to_string(), duration, memory)
+            }
+            PartId::P2 => {
+              let (out, duration, memory) = measured(|| solver.part2(parsed, name));
+              (out?.to_string(), duration, memory)
+            }
+          };
+          Ok((
+            out,
+            Measurement {
+              parse: parse_duration,
+              parse_reused,
+              solve: solve_duration,
+              memory: count_allocations.then_some([parse_memory, solve_memory]),
+            },
+          ))
+        })
+        .with_context(|| format!(" {RED}✕ {RESET}Part {part} error on {input}")),
+        Outcome::Skipped(reason) => Ok(Outcome::Skipped(reason.clone())),
+        Outcome::Panicked(message) | Outcome::AlreadyPanicked(message) if parse_reused => {
+          Ok(Outcome::AlreadyPanicked(message.clone()))
+        }
+        Outcome::Panicked(message) | Outcome::AlreadyPanicked(message) => {
+          Ok(Outcome::Panicked(message.clone()))
+        }
+        Outcome::TimedOut(elapsed) => Ok(Outcome::TimedOut(*elapsed)),
+        Outcome::AlreadyTimedOut => Ok(Outcome::AlreadyTimedOut),
+      };
+      if D::PARSE_ONCE {
+        *shared = Some(parsed);
+      }
+      result
+    };
+    thread::Builder::new()
+      .name(thread_name)
+      .stack_size(WORKER_STACK_SIZE)
+      .spawn(move || {
+        let mut shared = None;
+        for part in requests {
+          // If the runner already gave up, nobody is listening anymore.
+          if sender.send(solve(part, &mut shared)).is_err() {
+            break;
+          }
+        }
+      })
+      .context("Failed to start a thread for the solver")?;
+    Ok(InputWorker {
+      parts,
+      results,
+      timed_out: false,
+    })
+  }
+
+  fn solve(
+    &mut self,
+    part: PartId,
+    time_limit: Duration,
+  ) -> Result<Outcome<(String, Measurement)>> {
+    if self.timed_out {
+      return Ok(Outcome::AlreadyTimedOut);
+    }
+    let start = Instant::now();
+    self
+      .parts
+      .send(part)
+      .context("The solver's thread stopped without an answer")?;
+    match self.results.recv_timeout(time_limit) {
+      Ok(result) => result,
+      // There's no way to stop a thread from outside, so it's left running, and this input's other part is never started.
+      // The thread ends with the process.
+      Err(RecvTimeoutError::Timeout) => {
+        self.timed_out = true;
+        Ok(Outcome::TimedOut(start.elapsed()))
+      }
+      Err(RecvTimeoutError::Disconnected) => bail!("The solver's thread stopped without an answer"),
+    }
+  }
+}
+
+/// Solves `part` on the input's worker, starting the worker first if there isn't one yet.
+fn solve_input(
+  worker: &mut Option<InputWorker>,
+  part: PartId,
+  time_limit: Duration,
+  start: impl FnOnce() -> Result<InputWorker>,
+) -> Result<Outcome<(String, Measurement)>> {
+  match worker {
+    Some(worker) => worker.solve(part, time_limit),
+    None => worker.insert(start()?).solve(part, time_limit),
+  }
+}
+
+pub(crate) fn panic_message(payload: &Box<dyn Any + Send>) -> String {
+  if let Some(message) = payload.downcast_ref::<&str>() {
+    message.to_string()
+  } else if let Some(message) = payload.downcast_ref::<String>() {
+    message.clone()
+  } else {
+    "Panicked with a non-string payload".to_string()
+  }
+}
+
+/// Asking Cargo is slow enough to notice when a whole year runs in one process, so only ask once.
+fn workspace_root() -> Result<PathBuf> {
+  static WORKSPACE_ROOT: OnceLock<PathBuf> = OnceLock::new();
+  if let Some(root) = WORKSPACE_ROOT.get() {
+    return Ok(root.clone());
+  }
+  let binding = MetadataCommand::new()
+    .exec()
+    .context("Could not detect Cargo workspace root when loading inputs")?
+    .workspace_root;
+  Ok(
+    WORKSPACE_ROOT
+      .get_or_init(|| PathBuf::from(&binding))
+      .clone(),
+  )
+}
+
+/// Before measuring, each part is run repeatedly for at least this long to warm up caches.
+const BENCH_WARMUP: Duration = Duration::from_millis(200);
+
+/// Times `parse` and then `solve`, `iterations` times each, after a warmup.
+/// Everything goes through `black_box` so the optimizer can't skip work whose result is unused.
+fn bench_part<Parsed, Out>(
+  real_lines: &[String],
+  iterations: usize,
+  parse: impl Fn(Vec<String>) -> Result<Parsed>,
+  solve: impl Fn(&Parsed) -> Result<Out>,
+) -> Result<(BenchStats, BenchStats)> {
+  let warmup_start = Instant::now();
+  while warmup_start.elapsed() < BENCH_WARMUP {
+    let parsed = black_box(parse(black_box(real_lines.to_vec()))?);
+    black_box(solve(black_box(&parsed))?);
+  }
+
+  let mut parse_times = Vec::with_capacity(iterations);
+  for _ in 0..iterations {
+    // Copying the input is not part of what's being measured.
+    let lines = real_lines.to_vec();
+    let start = Instant::now();
+    let parsed = black_box(parse(black_box(lines))?);
+    parse_times.push(start.elapsed());
+    drop(parsed);
+  }
+
+  let parsed = parse(real_lines.to_vec())?;
+  let mut solve_times = Vec::with_capacity(iterations);
+  for _ in 0..iterations {
+    let start = Instant::now();
+    let out = black_box(solve(black_box(&parsed))?);
+    solve_times.push(start.elapsed());
+    drop(out);
+  }
+
+  Ok((
+    BenchStats::from_durations(parse_times),
+    BenchStats::from_durations(solve_times),
+  ))
+}
+
+fn input_lines(contents: &str) -> Vec<String> {
+  let mut out = contents
+    .split('\n')
+    .map(|s| s.to_string())
+    .collect::<Vec<String>>();
+
+  // Some puzzles have a blank line in the middle,
+  // and some have meaningful leading whitespace.
+  // Instead of trimming those, only remove blank
+  // lines from the beginning and end of the file.
+  // THOSE are never part of the puzzle (at least
+  // for the first ten years of Advent of Code).
+  // TODO: When let-chaining stabilizes, make a better condition check here.
+  while !out.is_empty() && out.last().unwrap().is_empty() {
+    out.pop();
+  }
+  while !out.is_empty() && out.first().unwrap().is_empty() {
+    out.remove(0);
+  }
+  out
+}
+
+/// Loads `--input` in place of the real input. With `--expect`, it's checked like a sample instead.
+fn load_custom_input(
+  path: &Path,
+  options: &RunOptions,
+) -> Result<(Vec<Sample>, Option<Vec<String>>, Params)> {
+  let (name, contents) = if path == Path::new("-") {
+    let mut contents = String::new();
+    io::stdin()
+      .read_to_string(&mut contents)
+      .context("Failed to read input from stdin")?;
+    ("stdin".to_string(), contents)
+  } else {
+    let contents =
+      fs::read_to_string(path).with_context(|| format!("Failed to read input {path:?}"))?;
+    let name = path
+      .file_name()
+      .map_or("input".into(), |n| n.to_string_lossy());
+    (name.to_string(), contents)
+  };
+  let lines = input_lines(&contents);
+
+  let Some(expect) = &options.expect else {
+    return Ok((
+      vec![],
+      if options.test { None } else { Some(lines) },
+      Params::default(),
+    ));
+  };
+  let expect = Some(input_lines(expect));
+  let (expect_lines_1, expect_lines_2) = match options.part {
+    Some(PartId::P2) => (None, expect),
+    _ => (expect, None),
+  };
+  let sample = Sample {
+    name,
+    lines,
+    expect_lines_1,
+    expect_lines_2,
+    params: Params::default(),
+  };
+  Ok((vec![sample], None, Params::default()))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
 pub enum PartId {
+  #[value(name = "1")]
   P1,
+  #[value(name = "2")]
   P2,
 }
 
-fn duration_string(duration: Duration) -> String {
-  if duration.as_micros() < 1 {
-    format!("{: >4}{CYAN}ns{RESET}", duration.subsec_nanos())
-  } else if duration.as_millis() < 1 {
-    format!("{: >4}{GREEN}µs{RESET}", duration.subsec_micros())
-  } else if duration.as_millis() <= 99 {
-    format!(
-      "{: >4.1}{BRIGHT_BLACK}ms{RESET}",
-      (duration.subsec_micros() as f64) / 1_000.0
-    )
-  } else if duration.as_millis() <= 999 {
-    format!("{: >4}{BRIGHT_BLACK}ms{RESET}", duration.subsec_millis())
-  } else if duration.as_secs() <= 9 {
-    format!(
-      "{: >3.1}{RED}sec{RESET}",
-      (duration.as_millis() as f64) / 1_000.0
-    )
-  } else {
-    format!("{} {RED}seconds{RESET}", duration.as_secs())
+impl PartId {
+  pub fn index(&self) -> usize {
+    match self {
+      PartId::P1 => 0,
+      PartId::P2 => 1,
+    }
+  }
+}
+
+impl std::fmt::Display for PartId {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      PartId::P1 => write!(f, "1"),
+      PartId::P2 => write!(f, "2"),
+    }
   }
 }
 
@@ -65,6 +845,20 @@ pub struct Sample {
   lines: Vec<String>,
   expect_lines_1: Option<Vec<String>>,
   expect_lines_2: Option<Vec<String>>,
+  params: Params,
+}
+
+/// How long a day gets for parsing and each part on one input, unless it declares its own `TIME_LIMIT`.
+pub const DEFAULT_TIME_LIMIT: Duration = Duration::from_secs(60);
+
+/// Whether `solver`'s day declares its own `TIME_LIMIT`, which marks it as one that can run away on bad input.
+pub const fn declares_time_limit<D, Parsed, P1, P2>(_solver: &D) -> bool
+where
+  D: Day<Parsed, P1, P2>,
+  P1: fmt::Debug + fmt::Display,
+  P2: fmt::Debug + fmt::Display,
+{
+  D::TIME_LIMIT.as_nanos() != DEFAULT_TIME_LIMIT.as_nanos()
 }
 
 pub trait Day<
@@ -78,11 +872,14 @@ pub trait Day<
   /// Turn it into whatever format you need here.
   ///
   /// If not set, the default implementation is a passthrough (only worked if Parsed is still Vec<String>!)
+  ///
+  /// `params` are the constants from this input's params file. Anything `part1` or `part2` needs from them goes in `Parsed`.
   fn parse(
     &self,
     raw: Vec<String>,
     sample_name: Option<String>,
     for_part: PartId,
+    params: &Params,
   ) -> Result<Parsed>;
 
   /// If sample_name is set, it declares _which_ sample is being run.
@@ -91,35 +888,32 @@ pub trait Day<
   /// If sample_name is set, it declares _which_ sample is being run.
   fn part2(&self, parsed: &Parsed, sample_name: Option<String>) -> Result<Part2Solution>;
 
-  fn load_inputs(&self, year: u16, day: u16) -> Result<(Vec<Sample>, Vec<String>)> {
-    let binding = MetadataCommand::new()
-      .exec()
-      .context("Could not detect Cargo workspace root when loading inputs")?
-      .workspace_root;
-    let workspace_root = PathBuf::from(&binding);
+  /// How long parsing and solving one part may take on one input before the runner gives up on it.
+  /// Days that are slow on purpose can raise it, and `--timeout` overrides it.
+  const TIME_LIMIT: Duration = DEFAULT_TIME_LIMIT;
+
+  /// Set when `parse` ignores `for_part`, so that each input is parsed once and the result shared by both parts.
+  const PARSE_ONCE: bool = false;
+
+  /// Loads every sample for the day, and the real input unless `options.test` is set,
+  /// along with the params for the real input.
+  fn load_inputs(
+    &self,
+    year: u16,
+    day: u16,
+    options: &RunOptions,
+  ) -> Result<(Vec<Sample>, Option<Vec<String>>, Params)> {
+    let workspace_root = workspace_root()?;
+
+    if let Some(path) = &options.input {
+      return load_custom_input(path, options);
+    }
 
     let load_input = |name: String| -> Result<Vec<String>> {
       let path = workspace_root.join(format!("{year}/input/day{day:0>2}.{name}.txt"));
-      let mut out = fs::read_to_string(&path)
-        .context("Failed to read input named {name}")?
-        .split('\n')
-        .map(|s| s.to_string())
-        .collect::<Vec<String>>();
-
-      // Some puzzles have a blank line in the middle,
-      // and some have meaningful leading whitespace.
-      // Instead of trimming those, only remove blank
-      // lines from the beginning and end of the file.
-      // THOSE are never part of the puzzle (at least
-      // for the first ten years of Advent of Code).
-      // TODO: When let-chaining stabilizes, make a better condition check here.
-      while !out.is_empty() && out.last().unwrap().is_empty() {
-        out.pop();
-      }
-      while !out.is_empty() && out.first().unwrap().is_empty() {
-        out.remove(0);
-      }
-      Ok(out)
+      let contents =
+        fs::read_to_string(&path).with_context(|| format!("Failed to read input named {name}"))?;
+      Ok(input_lines(&contents))
     };
 
     let re = Regex::new(format!("^day{day:0>2}\\.(?P<name>[^\\.]+)\\.sample\\.txt$").as_str())
@@ -131,149 +925,283 @@ pub trait Day<
     let sample_files = listing
       .into_iter()
       .filter_map(|l| l.ok())
-      .map(|f| {
+      .filter_map(|f| {
         let file_name = String::from(f.file_name().to_str().unwrap());
-        if let Some(caps) = re.captures(file_name.as_str()) {
-          let name = caps.name("name").unwrap().as_str().to_string();
-          let lines = load_input(format!("{name}.sample"))?;
-          let expect_lines_1 = load_input(format!("{name}.expect.1")).ok();
-          let expect_lines_2 = load_input(format!("{name}.expect.2")).ok();
-
-          Ok(Sample {
-            name,
-            lines,
-            expect_lines_1,
-            expect_lines_2,
-          })
-        } else {
-          bail!("That isn't a sample file");
-        }
+        let caps = re.captures(file_name.as_str())?;
+        Some(caps.name("name").unwrap().as_str().to_string())
+      })
+      .filter(|name| options.runs_sample(name))
+      .map(|name| {
+        let lines = load_input(format!("{name}.sample"))?;
+        let expect_lines_1 = load_input(format!("{name}.expect.1")).ok();
+        let expect_lines_2 = load_input(format!("{name}.expect.2")).ok();
+        let params = Params::load(&params::params_path(&workspace_root, year, day, &name))?;
+
+        Ok(Sample {
+          name,
+          lines,
+          expect_lines_1,
+          expect_lines_2,
+          params,
+        })
       })
-      .filter_map(|r| r.ok())
-      .collect::<Vec<Sample>>();
+      .collect::<Result<Vec<Sample>>>()?;
 
-    let real_lines = load_input("real".to_string())?;
+    let real_lines = if options.test {
+      None
+    } else {
+      Some(load_input("real".to_string())?)
+    };
+
+    let real_params = Params::load(&params::params_path(&workspace_root, year, day, "real"))?;
+
+    Ok((sample_files, real_lines, real_params))
+  }
+
+  /// Runs the samples and real input for both parts, using the options from the command line.
+  fn run(&'static self, year: u16, day: u16) -> Result<()>
+  where
+    Self: Sync,
+  {
+    run_from_command_line(|options| self.run_with_options(year, day, options))
+  }
+
+  /// Times parse and both parts on the real input, `options.bench` times each.
+  /// Samples are not checked, so run without `--bench` first to make sure the answers are right.
+  fn bench(&self, year: u16, day: u16, options: &RunOptions) -> Result<DaySummary> {
+    let iterations = options.bench.unwrap_or(1).max(1);
+    let reporter = Reporter::new(
+      year,
+      day,
+      options.format,
+      false,
+      options.ignore_trailing_whitespace,
+    );
+    reporter.header();
+
+    let (_, real_lines, real_params) = self.load_inputs(year, day, options)?;
+    let real_lines = real_lines.context("Benchmarking needs the real input")?;
+
+    if options.runs_part(PartId::P1) {
+      let (parse_stats, solve_stats) = bench_part(
+        &real_lines,
+        iterations,
+        |lines| self.parse(lines, None, PartId::P1, &real_params),
+        |parsed| self.part1(parsed, None),
+      )
+      .context("Part 1 error while benchmarking")?;
+      reporter.bench(PartId::P1, &parse_stats, &solve_stats);
+    }
+
+    if options.runs_part(PartId::P2) {
+      let (parse_stats, solve_stats) = bench_part(
+        &real_lines,
+        iterations,
+        |lines| self.parse(lines, None, PartId::P2, &real_params),
+        |parsed| self.part2(parsed, None),
+      )
+      .context("Part 2 error while benchmarking")?;
+      reporter.bench(PartId::P2, &parse_stats, &solve_stats);
+    }
 
-    Ok((sample_files, real_lines))
+    Ok(reporter.into_summary())
   }
 
-  fn run(&self, year: u16, day: u16) -> Result<()> {
-    println!("🎄 {BOLD}Advent of Code {GREEN}{year}{RESET}, Day {BOLD}{GREEN}{day}{RESET} 🎄");
+  fn run_with_options(
+    &'static self,
+    year: u16,
+    day: u16,
+    options: &RunOptions,
+  ) -> Result<DaySummary>
+  where
+    Self: Sync,
+  {
+    if options.bench.is_some() {
+      return self.bench(year, day, options);
+    }
+
+    let show_passing_samples = options.test || options.expect.is_some();
+    let reporter = Reporter::new(
+      year,
+      day,
+      options.format,
+      show_passing_samples,
+      options.ignore_trailing_whitespace,
+    );
+    reporter.header();
 
-    // Each input has to run through the code's parser twice, once for each part.
+    // Unless the day sets PARSE_ONCE, each input runs through the code's parser twice, once for each part.
     // For most days, this is redundant, but some days need slightly different parse
-    // logic for each part of the puzzle. For example, 2023 day 23 or 2024 day 15.
+    // logic for each part of the puzzle. For example, 2017 day 10 or 2025 day 6.
 
-    let (sample_files, real_lines) = &self.load_inputs(year, day)?;
+    let (sample_files, real_lines, real_params) = &self.load_inputs(year, day, options)?;
+    let workspace_root = workspace_root()?;
+    let ledger = if options.input.is_none() {
+      Ledger::load(&workspace_root, year, day)?
+    } else {
+      Ledger::default()
+    };
 
     let cold_start = Instant::now();
 
-    // Check that part 1 passes all samples. If it does, run it on the real input.
-    let mut part1_test_failures = 0;
-    for Sample {
-      name,
-      lines,
-      expect_lines_1,
-      ..
-    } in sample_files.iter()
-    {
-      if expect_lines_1.is_none() {
-        continue;
-      }
-      let parsed = &self
-        .parse(lines.clone(), Some(name.to_string()), PartId::P1)
-        .with_context(|| format!("Parsing error for sample {name}"))?;
-      let out = &self
-        .part1(parsed, Some(name.to_string()))
-        .with_context(|| format!(" {RED}✕ {RESET}Part 1 error on sample {YELLOW}{name}{RESET}"))?;
-      let out_string = out.to_string();
-      let expect_string = expect_lines_1.to_owned().unwrap().join("\n");
-      if out_string == expect_string {
-        // TODO: some test_only flag that renders this line and skips running against real
-        // println!(" {GREEN}✓ {RESET}Part 1 test {YELLOW}{sample_name}{RESET} {GREEN}passed{RESET}");
-      } else {
-        eprintln!(
-          " {RED}✕ {RESET}Part 1 test {YELLOW}{name}{RESET} {RED}failed{RESET}\n   {GREEN}Expected: {RESET}{expect_string}\n   {RED}Received: {RESET}{out_string}"
-        );
-        part1_test_failures += 1;
-      }
-    }
-    if part1_test_failures == 0 {
-      let parse_start = Instant::now();
-      let parsed = &self
-        .parse(real_lines.clone(), None, PartId::P1)
-        .context("Parse error on real input")?;
-      let parse_duration = parse_start.elapsed();
-      let solve_start = Instant::now();
-      let out = &self
-        .part1(parsed, None)
-        .with_context(|| format!(" {RED}✕ {RESET}Part 1 error on real data"))?;
-      let solve_duration = solve_start.elapsed();
-      let time_str = format!(
-        "{BRIGHT_BLACK}(Parse {RESET}{}{BRIGHT_BLACK}, Solve {RESET}{}{BRIGHT_BLACK}){RESET}",
-        duration_string(parse_duration),
-        duration_string(solve_duration)
+    if options.memory && !memory::is_counting() {
+      bail!(
+        "--memory needs the counting allocator, which the `advent_lib/count-allocations` feature installs"
       );
-      println!(" {BOLD}{BRIGHT_YELLOW}★ {RESET}Star 1 {time_str}: {YELLOW}{out}{RESET}");
-    } else {
-      eprintln!(" {RED}★ {RESET}Star 1: {RED}{part1_test_failures:?} failed test(s){RESET}");
     }
 
-    // Same process for part 2.
+    let mut part1_test_failures = 0;
     let mut part2_test_failures = 0;
-    for Sample {
-      name,
-      lines,
-      expect_lines_2,
-      ..
-    } in sample_files.iter()
-    {
-      if expect_lines_2.is_none() {
+    let time_limit = options.timeout.unwrap_or(Self::TIME_LIMIT);
+    // One per input, kept across both parts so that a shared parse can be reused.
+    let mut sample_workers: Vec<Option<InputWorker>> = sample_files.iter().map(|_| None).collect();
+    let mut real_worker: Option<InputWorker> = None;
+
+    // Recorded up front, so that a part that wasn't asked for never looks like it crashed.
+    for part in [PartId::P1, PartId::P2] {
+      if !options.runs_part(part) {
+        reporter.not_run(part);
+      }
+    }
+
+    // Check that each part passes all samples. If it does, run it on the real input.
+    for part in [PartId::P1, PartId::P2] {
+      if !options.runs_part(part) {
         continue;
       }
-      let parsed = &self
-        .parse(lines.clone(), Some(name.to_string()), PartId::P2)
-        .with_context(|| format!("Parsing error for sample {name}"))?;
-      let out = &self
-        .part2(parsed, Some(name.to_string()))
-        .with_context(|| format!(" {RED}✕ {RESET}Part 2 error on sample {YELLOW}{name}{RESET}"))?;
-      let out_string = out.to_string();
-      let expect_string = expect_lines_2.to_owned().unwrap().join("\n");
-      if out_string == expect_string {
-        // TODO: some test_only flag that renders this line and skips running against real
-        // println!(" {GREEN}✓ {RESET}Part 2 test {YELLOW}{name}{RESET} {GREEN}passed{RESET}");
+      let test_failures = match part {
+        PartId::P1 => &mut part1_test_failures,
+        PartId::P2 => &mut part2_test_failures,
+      };
+      for (
+        Sample {
+          name,
+          lines,
+          expect_lines_1,
+          expect_lines_2,
+          params,
+        },
+        worker,
+      ) in sample_files.iter().zip(sample_workers.iter_mut())
+      {
+        let Some(expect_lines) = (match part {
+          PartId::P1 => expect_lines_1,
+          PartId::P2 => expect_lines_2,
+        }) else {
+          continue;
+        };
+        let (out_string, measurement) = match solve_input(worker, part, time_limit, || {
+          InputWorker::start(self, Some(name), lines.clone(), params, options.memory)
+        })? {
+          Outcome::Done(done) => done,
+          Outcome::Skipped(reason) => {
+            reporter.sample_skipped(part, name, &reason);
+            continue;
+          }
+          Outcome::Panicked(message) => {
+            reporter.sample_panicked(part, name, &message, false);
+            *test_failures += 1;
+            continue;
+          }
+          Outcome::AlreadyPanicked(message) => {
+            reporter.sample_panicked(part, name, &message, true);
+            *test_failures += 1;
+            continue;
+          }
+          Outcome::TimedOut(elapsed) => {
+            reporter.sample_timed_out(part, name, elapsed);
+            *test_failures += 1;
+            continue;
+          }
+          Outcome::AlreadyTimedOut => {
+            reporter.sample_abandoned(part, name);
+            *test_failures += 1;
+            continue;
+          }
+        };
+        let expect_string = expect_lines.join("\n");
+        if answers::same_answer(
+          &out_string,
+          &expect_string,
+          options.ignore_trailing_whitespace,
+        ) {
+          reporter.sample_passed(part, name, &expect_string, &out_string, measurement);
+        } else {
+          reporter.sample_failed(part, name, &expect_string, &out_string, measurement);
+          *test_failures += 1;
+        }
+      }
+      if let Some(real_lines) = real_lines
+        && *test_failures == 0
+      {
+        let (out_string, measurement) =
+          match solve_input(&mut real_worker, part, time_limit, || {
+            InputWorker::start(self, None, real_lines.clone(), real_params, options.memory)
+          })? {
+            Outcome::Done(done) => done,
+            Outcome::Skipped(reason) => {
+              reporter.star_skipped(part, &reason);
+              continue;
+            }
+            Outcome::Panicked(message) => {
+              reporter.star_panicked(part, &message, false);
+              continue;
+            }
+            Outcome::AlreadyPanicked(message) => {
+              reporter.star_panicked(part, &message, true);
+              continue;
+            }
+            Outcome::TimedOut(elapsed) => {
+              reporter.star_timed_out(part, elapsed);
+              continue;
+            }
+            Outcome::AlreadyTimedOut => {
+              reporter.star_abandoned(part);
+              continue;
+            }
+          };
+        // Answers and timings for some other input say nothing about the real one.
+        let known = if options.input.is_none() {
+          answers::record_latest_answer(&workspace_root, year, day, part, &out_string)?;
+          // Counting allocations slows everything down, so those timings would look like a regression.
+          if !options.memory {
+            history::append(
+              &workspace_root,
+              year,
+              day,
+              part,
+              // A reused parse is recorded as such, so the year's total only counts it once.
+              (!measurement.parse_reused).then_some(measurement.parse),
+              measurement.solve,
+            )?;
+          }
+          answers::read_known_answer(&workspace_root, year, day, part)?
+        } else {
+          None
+        };
+        if let Some(earlier) = ledger.rules_out(part, answers::normalize(&out_string)) {
+          reporter.ruled_out(part, earlier);
+        }
+        reporter.star(part, &out_string, known.as_deref(), measurement);
+        if options.accept {
+          answers::record_answer(&workspace_root, year, day, part, &out_string)?;
+          reporter.accepted(part);
+        }
+      } else if *test_failures > 0 {
+        reporter.star_failed(part, *test_failures);
       } else {
-        eprintln!(
-          " {RED}✕ {RESET}Part 2 test {YELLOW}{name}{RESET} {RED}failed{RESET}\n   {GREEN}Expected: {RESET}{expect_string}\n   {RED}Received: {RESET}{out_string}"
-        );
-        part2_test_failures += 1;
+        reporter.samples_passed(part);
       }
     }
-    if part2_test_failures == 0 {
-      let parse_start = Instant::now();
-      let parsed = &self
-        .parse(real_lines.clone(), None, PartId::P2)
-        .context("Parse error on real input")?;
-      let parse_duration = parse_start.elapsed();
-      let solve_start = Instant::now();
-      let out = &self
-        .part2(parsed, None)
-        .with_context(|| format!(" {RED}✕ {RESET}Part 2 error on real data"))?;
-      let solve_duration = solve_start.elapsed();
-      let time_str = format!(
-        "{BRIGHT_BLACK}(Parse {RESET}{}{BRIGHT_BLACK}, Solve {RESET}{}{BRIGHT_BLACK}){RESET}",
-        duration_string(parse_duration),
-        duration_string(solve_duration)
+
+    reporter.total(cold_start.elapsed());
+
+    if options.test && part1_test_failures + part2_test_failures > 0 {
+      bail!(
+        "{} sample(s) failed",
+        part1_test_failures + part2_test_failures
       );
-      println!(" {BOLD}{BRIGHT_YELLOW}★ {RESET}Star 2 {time_str}: {YELLOW}{out}{RESET}");
-    } else {
-      eprintln!(" {RED}★ {RESET}Star 2: {RED}{part2_test_failures:?} failed test(s){RESET}");
     }
-
-    println!(
-      "⌛{BOLD}{BRIGHT_BLACK} Total:{RESET}{}",
-      duration_string(cold_start.elapsed())
-    );
-    Ok(())
+    Ok(reporter.into_summary())
   }
 }
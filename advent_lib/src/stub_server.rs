@@ -0,0 +1,86 @@
+//! A stand-in for Advent of Code for tests to point an `AocClient` at, serving canned pages on a local port.
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// One request the server got.
+#[derive(Clone, Debug)]
+pub struct Request {
+  pub method: String,
+  pub path: String,
+  /// Keyed by lowercase name.
+  pub headers: HashMap<String, String>,
+  pub body: String,
+}
+
+pub struct StubServer {
+  pub base_url: String,
+  requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl StubServer {
+  /// Answers every request to each path with its status and body, and anything else with a 404.
+  /// The server runs until the test process ends.
+  pub fn start(routes: &[(&str, u16, &str)]) -> StubServer {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let routes: HashMap<String, (u16, String)> = routes
+      .iter()
+      .map(|(path, status, body)| (path.to_string(), (*status, body.to_string())))
+      .collect();
+    let requests = Arc::new(Mutex::new(vec![]));
+    let seen = Arc::clone(&requests);
+    thread::spawn(move || {
+      for stream in listener.incoming().flatten() {
+        let request = read_request(&stream);
+        let (status, body) = routes
+          .get(&request.path)
+          .cloned()
+          .unwrap_or((404, "Not found".to_string()));
+        seen.lock().unwrap().push(request);
+        let mut stream = stream;
+        let _ = write!(
+          stream,
+          "HTTP/1.1 {status} Stub\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+          body.len()
+        );
+      }
+    });
+    StubServer { base_url, requests }
+  }
+
+  pub fn requests(&self) -> Vec<Request> {
+    self.requests.lock().unwrap().clone()
+  }
+}
+
+fn read_request(stream: &TcpStream) -> Request {
+  let mut reader = BufReader::new(stream);
+  let mut line = String::new();
+  reader.read_line(&mut line).unwrap();
+  let mut words = line.split_whitespace();
+  let method = words.next().unwrap_or_default().to_string();
+  let path = words.next().unwrap_or_default().to_string();
+  let mut headers = HashMap::new();
+  loop {
+    line.clear();
+    reader.read_line(&mut line).unwrap();
+    let Some((name, value)) = line.trim_end().split_once(':') else {
+      break;
+    };
+    headers.insert(name.to_ascii_lowercase(), value.trim().to_string());
+  }
+  let length = headers
+    .get("content-length")
+    .map_or(0, |l| l.parse().unwrap());
+  let mut body = vec![0; length];
+  reader.read_exact(&mut body).unwrap();
+  Request {
+    method,
+    path,
+    headers,
+    body: String::from_utf8(body).unwrap(),
+  }
+}
@@ -0,0 +1,395 @@
+//! Sends answers to Advent of Code, and makes sense of the page it sends back.
+use crate::answers;
+use crate::color::*;
+use crate::credentials;
+use crate::http::{self, AocClient};
+use crate::ledger::{Ledger, LedgerEntry, LedgerVerdict};
+use crate::runner::PartId;
+use anyhow::{Context, Result, bail};
+use regex::Regex;
+use std::fmt;
+use std::path::Path;
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Hint {
+  TooHigh,
+  TooLow,
+}
+
+/// What the server said about a submitted answer.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Verdict {
+  Correct,
+  Wrong {
+    hint: Option<Hint>,
+  },
+  /// Submitted too soon after a previous answer, nothing was checked.
+  TooRecent {
+    wait: Option<Duration>,
+  },
+  /// The part was already solved, or part 1 isn't solved yet.
+  AlreadyCompleted,
+  /// Anything else, with the text of the response so it can be shown as-is.
+  Unknown(String),
+}
+
+impl fmt::Display for Verdict {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Verdict::Correct => write!(f, "{GREEN} ★ {BOLD}That's the right answer!{RESET}"),
+      Verdict::Wrong {
+        hint: Some(Hint::TooHigh),
+      } => {
+        write!(f, "{RED} ✕ {BOLD}Wrong answer{RESET}, it's too high")
+      }
+      Verdict::Wrong {
+        hint: Some(Hint::TooLow),
+      } => {
+        write!(f, "{RED} ✕ {BOLD}Wrong answer{RESET}, it's too low")
+      }
+      Verdict::Wrong { hint: None } => write!(f, "{RED} ✕ {BOLD}Wrong answer{RESET}"),
+      Verdict::TooRecent { wait: Some(wait) } => write!(
+        f,
+        "{YELLOW} • {BOLD}Answered too recently{RESET}, wait {}s before trying again",
+        wait.as_secs()
+      ),
+      Verdict::TooRecent { wait: None } => {
+        write!(
+          f,
+          "{YELLOW} • {BOLD}Answered too recently{RESET}, wait before trying again"
+        )
+      }
+      Verdict::AlreadyCompleted => write!(
+        f,
+        "{YELLOW} • {BOLD}Not the right level{RESET}, this part is already done or not unlocked yet"
+      ),
+      Verdict::Unknown(text) => write!(f, "{YELLOW} • {BOLD}Unrecognized response:{RESET}\n{text}"),
+    }
+  }
+}
+
+/// Reads the verdict out of the HTML page the server responds with.
+pub fn parse_response(body: &str) -> Verdict {
+  let text = article_text(body);
+  if text.contains("That's the right answer") {
+    Verdict::Correct
+  } else if text.contains("That's not the right answer") {
+    let hint = if text.contains("too high") {
+      Some(Hint::TooHigh)
+    } else if text.contains("too low") {
+      Some(Hint::TooLow)
+    } else {
+      None
+    };
+    Verdict::Wrong { hint }
+  } else if text.contains("You gave an answer too recently") {
+    let wait_re = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+    let wait = wait_re.captures(&text).map(|caps| {
+      let minutes: u64 = caps.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+      let seconds: u64 = caps[2].parse().unwrap();
+      Duration::from_secs(minutes * 60 + seconds)
+    });
+    Verdict::TooRecent { wait }
+  } else if text.contains("You don't seem to be solving the right level") {
+    Verdict::AlreadyCompleted
+  } else {
+    Verdict::Unknown(text)
+  }
+}
+
+/// The readable text inside the page's `<article>`, or the whole page without tags if there isn't one.
+fn article_text(body: &str) -> String {
+  let article_re = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+  let inner = article_re
+    .captures(body)
+    .map_or(body, |caps| caps.get(1).unwrap().as_str());
+  let tag_re = Regex::new(r"<[^>]*>").unwrap();
+  tag_re.replace_all(inner, "").trim().to_string()
+}
+
+/// POSTs one answer and parses the response. Does not touch any files.
+pub fn submit_answer(
+  client: &AocClient,
+  cookie: &str,
+  year: u16,
+  day: u16,
+  part: PartId,
+  answer: &str,
+) -> Result<Verdict> {
+  let level = part.to_string();
+  let form = [("level", level.as_str()), ("answer", answer)];
+  let response = client
+    .post_form(
+      &format!("/{year}/day/{day}/answer"),
+      cookie,
+      &form,
+      http::ANSWER,
+    )
+    .context("Failed to submit answer")?;
+
+  let status = response.status;
+  if response.body.contains("Please log in") || status == 500 {
+    bail!("Server says you aren't logged in, cookie is likely stale");
+  } else if !response.is_success() {
+    bail!("Server gave a {status} response");
+  }
+  Ok(parse_response(&response.body))
+}
+
+/// Submits an answer for the real input, by default the latest one the solution computed.
+/// Refuses answers that an earlier submission already ruled out, and records the verdict in the day's ledger.
+/// A correct answer is also recorded as the known-good answer for future runs.
+pub fn submit(
+  year: u16,
+  day: u16,
+  part: PartId,
+  answer: Option<&str>,
+  workspace_root: &Path,
+  client: &AocClient,
+  account: &str,
+) -> Result<Verdict> {
+  submit_with_cookie(year, day, part, answer, workspace_root, client, || {
+    credentials::session_cookie(workspace_root, client, account)
+  })
+}
+
+/// `submit`, asking `cookie` for the session cookie only once the answer is known to be worth sending.
+fn submit_with_cookie(
+  year: u16,
+  day: u16,
+  part: PartId,
+  answer: Option<&str>,
+  workspace_root: &Path,
+  client: &AocClient,
+  cookie: impl FnOnce() -> Result<String>,
+) -> Result<Verdict> {
+  let answer = match answer {
+    Some(answer) => answers::normalize(answer).to_string(),
+    None => answers::read_latest_answer(workspace_root, year, day, part)?.with_context(|| {
+      format!("No answer computed yet for part {part}, run the solution on the real input first")
+    })?,
+  };
+  if answer.is_empty() {
+    bail!("Refusing to submit an empty answer");
+  }
+
+  let ledger = Ledger::load(workspace_root, year, day)?;
+  if let Some(earlier) = ledger.rules_out(part, &answer) {
+    bail!("Refusing to submit {answer}, it is ruled out by the earlier submission {earlier}");
+  }
+
+  let cookie = cookie()?;
+
+  println!(
+    "{BRIGHT_BLACK} • Submitting {RESET}{BOLD}{answer}{RESET}{BRIGHT_BLACK} for part {part}{RESET}"
+  );
+  let verdict = submit_answer(client, &cookie, year, day, part, &answer)?;
+  let ledger_verdict = match verdict {
+    Verdict::Correct => Some(LedgerVerdict::Correct),
+    Verdict::Wrong { hint: None } => Some(LedgerVerdict::Wrong),
+    Verdict::Wrong {
+      hint: Some(Hint::TooHigh),
+    } => Some(LedgerVerdict::TooHigh),
+    Verdict::Wrong {
+      hint: Some(Hint::TooLow),
+    } => Some(LedgerVerdict::TooLow),
+    // The answer wasn't checked, so there's nothing to learn from these.
+    Verdict::TooRecent { .. } | Verdict::AlreadyCompleted | Verdict::Unknown(_) => None,
+  };
+  if let Some(verdict) = ledger_verdict {
+    let entry = LedgerEntry {
+      part,
+      verdict,
+      answer: answer.clone(),
+    };
+    Ledger::append(workspace_root, year, day, &entry)?;
+  }
+  if verdict == Verdict::Correct {
+    answers::record_answer(workspace_root, year, day, part, &answer)?;
+    // Solving a part changes the puzzle page.
+    client.invalidate(&format!("/{year}/day/{day}"))?;
+  }
+  Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::ledger;
+  use crate::stub_server::StubServer;
+  use std::fs;
+  use tempfile::TempDir;
+
+  const CORRECT: &str = "<main><article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to saving Christmas. [<a href=\"/2015/day/1#part2\">Continue to Part Two</a>]</p></article></main>";
+  const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again. [<a href=\"/2015/day/1\">Return to Day 1</a>]</p></article></main>";
+  const TOO_LOW: &str = "<main><article><p>That's not the right answer; your answer is too low.  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again. [<a href=\"/2015/day/1\">Return to Day 1</a>]</p></article></main>";
+  const TOO_RECENT: &str = "<main><article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 34s left to wait. [<a href=\"/2015/day/1\">Return to Day 1</a>]</p></article></main>";
+  const ALREADY_COMPLETED: &str = "<main><article><p>You don't seem to be solving the right level.  Did you already complete it? [<a href=\"/2015/day/1\">Return to Day 1</a>]</p></article></main>";
+
+  /// How many responses are in the cache.
+  fn cached_responses(dir: &Path) -> usize {
+    fs::read_dir(dir).map_or(0, |entries| {
+      entries
+        .flatten()
+        .map(|entry| {
+          if entry.file_type().unwrap().is_dir() {
+            cached_responses(&entry.path())
+          } else {
+            1
+          }
+        })
+        .sum()
+    })
+  }
+
+  /// Submits `answer` for 2015 day 1 part 1 to a server that responds with `page`,
+  /// from a workspace that already has the puzzle page cached.
+  fn submit_to(page: &str, answer: &str) -> (Verdict, StubServer, TempDir) {
+    let server = StubServer::start(&[
+      (
+        "/2015/day/1",
+        200,
+        "<article class=\"day-desc\">Part 1</article>",
+      ),
+      ("/2015/day/1/answer", 200, page),
+    ]);
+    let root = TempDir::new().unwrap();
+    fs::create_dir_all(root.path().join("2015/input")).unwrap();
+    let client = AocClient::with_base_url("test", &server.base_url)
+      .unwrap()
+      .with_cache_root(&root.path().join("cache"));
+    client
+      .get("/2015/day/1", "cookie", http::PUZZLE_PAGE)
+      .unwrap();
+    assert_eq!(cached_responses(&root.path().join("cache/responses")), 1);
+    let verdict = submit_with_cookie(
+      2015,
+      1,
+      PartId::P1,
+      Some(answer),
+      root.path(),
+      &client,
+      || Ok("cookie".to_string()),
+    )
+    .unwrap();
+    (verdict, server, root)
+  }
+
+  fn ledger_entries(root: &TempDir) -> Vec<LedgerEntry> {
+    Ledger::load(root.path(), 2015, 1).unwrap().entries
+  }
+
+  #[test]
+  fn correct_answer_is_recorded() {
+    let (verdict, server, root) = submit_to(CORRECT, "280");
+    assert_eq!(verdict, Verdict::Correct);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[1].method, "POST");
+    assert_eq!(requests[1].path, "/2015/day/1/answer");
+    assert_eq!(requests[1].headers["cookie"], "session=cookie");
+    assert_eq!(requests[1].body, "level=1&answer=280");
+
+    assert_eq!(
+      ledger_entries(&root),
+      vec![LedgerEntry {
+        part: PartId::P1,
+        verdict: LedgerVerdict::Correct,
+        answer: "280".to_string(),
+      }]
+    );
+    assert_eq!(
+      answers::read_known_answer(root.path(), 2015, 1, PartId::P1).unwrap(),
+      Some("280".to_string())
+    );
+    // The page gains part 2, so the cached copy is thrown out.
+    assert_eq!(cached_responses(&root.path().join("cache/responses")), 0);
+  }
+
+  #[test]
+  fn too_high_answer_is_ruled_out() {
+    let (verdict, _server, root) = submit_to(TOO_HIGH, "300");
+    assert_eq!(
+      verdict,
+      Verdict::Wrong {
+        hint: Some(Hint::TooHigh)
+      }
+    );
+    assert_eq!(ledger_entries(&root)[0].verdict, LedgerVerdict::TooHigh);
+    assert_eq!(
+      answers::read_known_answer(root.path(), 2015, 1, PartId::P1).unwrap(),
+      None
+    );
+    let ledger = Ledger::load(root.path(), 2015, 1).unwrap();
+    assert!(ledger.rules_out(PartId::P1, "301").is_some());
+    assert!(ledger.rules_out(PartId::P1, "299").is_none());
+  }
+
+  #[test]
+  fn too_low_answer_is_ruled_out() {
+    let (verdict, _server, root) = submit_to(TOO_LOW, "200");
+    assert_eq!(
+      verdict,
+      Verdict::Wrong {
+        hint: Some(Hint::TooLow)
+      }
+    );
+    assert_eq!(ledger_entries(&root)[0].verdict, LedgerVerdict::TooLow);
+    let ledger = Ledger::load(root.path(), 2015, 1).unwrap();
+    assert!(ledger.rules_out(PartId::P1, "199").is_some());
+    assert!(ledger.rules_out(PartId::P1, "201").is_none());
+  }
+
+  #[test]
+  fn too_recent_answer_gives_the_wait() {
+    let (verdict, _server, root) = submit_to(TOO_RECENT, "280");
+    assert_eq!(
+      verdict,
+      Verdict::TooRecent {
+        wait: Some(Duration::from_secs(94))
+      }
+    );
+    // Nothing was checked, so nothing is learned.
+    assert!(ledger_entries(&root).is_empty());
+  }
+
+  #[test]
+  fn already_completed_is_not_recorded() {
+    let (verdict, _server, root) = submit_to(ALREADY_COMPLETED, "280");
+    assert_eq!(verdict, Verdict::AlreadyCompleted);
+    assert!(ledger_entries(&root).is_empty());
+    assert_eq!(cached_responses(&root.path().join("cache/responses")), 1);
+    assert_eq!(
+      answers::read_known_answer(root.path(), 2015, 1, PartId::P1).unwrap(),
+      None
+    );
+  }
+
+  #[test]
+  fn ruled_out_answer_is_never_sent() {
+    let server = StubServer::start(&[("/2015/day/1/answer", 200, CORRECT)]);
+    let root = TempDir::new().unwrap();
+    fs::create_dir_all(root.path().join("2015/input")).unwrap();
+    fs::write(
+      ledger::ledger_path(root.path(), 2015, 1),
+      "1\ttoo-high\t300\n",
+    )
+    .unwrap();
+    let client = AocClient::with_base_url("test", &server.base_url)
+      .unwrap()
+      .with_cache_root(&root.path().join("cache"));
+    let result = submit_with_cookie(
+      2015,
+      1,
+      PartId::P1,
+      Some("400"),
+      root.path(),
+      &client,
+      || panic!("The cookie isn't needed for an answer that won't be sent"),
+    );
+    assert!(result.is_err());
+    assert!(server.requests().is_empty());
+  }
+}
@@ -0,0 +1,94 @@
+//! `aoc --watch DAY` reruns a day every time its code or its inputs change.
+use crate::color::*;
+use crate::paths::{RelevantPaths, get_relevant_paths};
+use crate::runner::{self, RunOptions};
+use anyhow::{Context, Result};
+use notify::{Event, RecursiveMode, Watcher};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+
+/// Saving a file often changes it several times in a row, so a run waits until changes stop for this long.
+const SETTLE_TIME: Duration = Duration::from_millis(100);
+
+/// Whether a change to `path` could change what the day's run does.
+fn is_relevant(path: &Path, day: u16, paths: &RelevantPaths) -> bool {
+  let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+    return false;
+  };
+  if path.starts_with(&paths.year_input) {
+    // Only the files a run reads. The answers it records would otherwise start another run.
+    name.starts_with(&format!("day{day:0>2}."))
+      && (name.ends_with(".sample.txt")
+        || name.contains(".expect.")
+        || name.ends_with(".real.txt")
+        || name.ends_with(".params.toml"))
+  } else if path.starts_with(&paths.src_bin) {
+    path == paths.day_rs
+  } else {
+    name.ends_with(".rs")
+  }
+}
+
+/// Blocks until something relevant changes, then until the changes settle down.
+fn wait_for_change(
+  changes: &Receiver<notify::Result<Event>>,
+  day: u16,
+  paths: &RelevantPaths,
+) -> Result<()> {
+  loop {
+    match changes.recv().context("Stopped getting file changes")? {
+      Ok(event)
+        if !event.kind.is_access() && event.paths.iter().any(|p| is_relevant(p, day, paths)) =>
+      {
+        break;
+      }
+      Ok(_) => {}
+      Err(err) => eprintln!(" {YELLOW}⚠ {RESET}Error while watching for changes: {err}"),
+    }
+  }
+  while changes.recv_timeout(SETTLE_TIME).is_ok() {}
+  Ok(())
+}
+
+/// Runs the day like `exec_day`, then again every time its solution, the year's library code,
+/// `advent_lib`, or its inputs change. Only stops when interrupted.
+pub fn watch_day(
+  year: u16,
+  day: u16,
+  workspace_root: &PathBuf,
+  options: &RunOptions,
+) -> Result<()> {
+  let paths = get_relevant_paths(year, day, workspace_root);
+  let (sender, changes) = mpsc::channel();
+  let mut watcher =
+    notify::recommended_watcher(sender).context("Failed to start watching for changes")?;
+  for (dir, mode) in [
+    (
+      workspace_root.join(format!("{year}/src")),
+      RecursiveMode::Recursive,
+    ),
+    (
+      workspace_root.join("advent_lib/src"),
+      RecursiveMode::Recursive,
+    ),
+    (paths.year_input.clone(), RecursiveMode::NonRecursive),
+  ] {
+    watcher
+      .watch(&dir, mode)
+      .with_context(|| format!("Failed to watch {dir:?}"))?;
+  }
+
+  loop {
+    // Clear the screen and move the cursor to the top, so each run starts on a fresh screen.
+    print!("\x1b[2J\x1b[H");
+    io::stdout().flush()?;
+    // Compile errors and failures are already printed by then, so they don't stop the watching.
+    if runner::exec_day(year, day, workspace_root, options).is_err() {
+      eprintln!("{RED} ✕ {BOLD}RUNNER FAILED{RESET}");
+    }
+    println!("{BRIGHT_BLACK}Watching for changes to day {day}, press Ctrl+C to stop...{RESET}");
+    wait_for_change(&changes, day, &paths)?;
+  }
+}
@@ -1,7 +1,17 @@
 #!/usr/bin/env bash
 adventOfCode() {
   cd "$(dirname "$(realpath "$0")")"
-  exec cargo -q run --release -- $@
+  # Whole-year runs are faster with every solution compiled in, see advent_lib/src/registry.rs.
+  # Single days skip that, so only the day being worked on has to compile.
+  local features=""
+  case " $* " in
+    *" --all "* | *" --all-years "*) features="--features registry" ;;
+  esac
+  # Runs in this process need the counting allocator too, see advent_lib/src/memory.rs.
+  case " $* " in
+    *" --memory "*) features="$features --features advent_lib/count-allocations" ;;
+  esac
+  exec cargo -q run --release $features -- $@
 }
 
 adventOfCode $@
@@ -1,29 +1,214 @@
-use advent_lib::{bootstrap, color::*, runner};
-use anyhow::{Context, Result};
+use advent_lib::{
+  bootstrap, color::*, credentials, history, http, puzzle, registry::YearRegistry, runner, submit,
+  watch,
+};
+use anyhow::{Context, Result, bail};
 use cargo_metadata::MetadataCommand;
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use jiff::Zoned;
 use std::path::PathBuf;
+use std::time::Duration;
 
 #[derive(Parser, Debug)]
-#[command(version, about)]
+#[command(version, about, args_conflicts_with_subcommands = true)]
 struct CommandLineArgs {
+  #[command(subcommand)]
+  command: Option<Command>,
   #[arg(long, short)]
   year: Option<u16>,
-  day: u16,
+  #[arg(required_unless_present_any = ["all", "all_years"])]
+  day: Option<u16>,
+  /// Run every day of the year and print a summary table
+  // The table only has room for pass or fail and times, so it can't show other formats or allocation counts.
+  #[arg(long, conflicts_with_all = ["day", "format", "memory"])]
+  all: bool,
+  /// Run every day of every year and print a summary table for each
+  #[arg(long, conflicts_with_all = ["day", "year", "all", "format", "memory"])]
+  all_years: bool,
+  /// Rebuild and rerun the day every time its code or inputs change
+  #[arg(long, conflicts_with_all = ["all", "all_years"])]
+  watch: bool,
+  /// Which stored session cookie to use with Advent of Code
+  #[arg(long, global = true, env = "AOC_ACCOUNT", default_value = credentials::DEFAULT_ACCOUNT)]
+  account: String,
+  #[command(flatten)]
+  run: runner::RunOptions,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+  /// Submit an answer for the real input to Advent of Code
+  Submit {
+    #[arg(long, short)]
+    year: Option<u16>,
+    day: u16,
+    #[arg(long, short, value_enum)]
+    part: runner::PartId,
+    /// Submit this instead of the latest answer the solution computed
+    #[arg(long, allow_hyphen_values = true)]
+    answer: Option<String>,
+    /// Where to send the answer, such as a local stand-in server for testing
+    #[arg(long, env = "AOC_BASE_URL", default_value = http::DEFAULT_BASE_URL)]
+    base_url: String,
+  },
+  /// Compare each part's latest time on the real input with earlier runs, and the year's total with a budget
+  PerfReport {
+    #[arg(long, short)]
+    year: Option<u16>,
+    /// Flag a part that got more than this many percent slower
+    #[arg(long, value_name = "PERCENT", default_value_t = 20.0)]
+    threshold: f64,
+    /// Which earlier run to compare with
+    #[arg(long, value_enum, default_value_t)]
+    against: history::Baseline,
+    /// Flag the year if all of its parts together take longer than this many seconds
+    #[arg(long, value_name = "SECONDS", env = "AOC_TIME_BUDGET")]
+    budget: Option<f64>,
+  },
+  /// Show a day's puzzle description, from the copy saved next to the inputs
+  Read {
+    #[arg(long, short)]
+    year: Option<u16>,
+    day: u16,
+    /// Download it again, such as to get part 2 after solving part 1
+    #[arg(long)]
+    refresh: bool,
+  },
+  /// Run one day of a year compiled into this binary, which is how `--all` runs each day in its own process
+  #[command(name = runner::RUN_DAY_COMMAND, hide = true)]
+  RunDay {
+    year: u16,
+    day: u16,
+    #[command(flatten)]
+    run: runner::RunOptions,
+  },
+}
+
+/// The years whose solutions were compiled into this binary, so they can run in-process.
+/// Without the `registry` feature, every day runs as its own child process instead.
+#[cfg(feature = "registry")]
+fn registries() -> Vec<YearRegistry> {
+  vec![
+    advent_of_code_2015::DAYS,
+    advent_of_code_2016::DAYS,
+    advent_of_code_2017::DAYS,
+    advent_of_code_2018::DAYS,
+    advent_of_code_2019::DAYS,
+    advent_of_code_2020::DAYS,
+    advent_of_code_2025::DAYS,
+  ]
+}
+
+#[cfg(not(feature = "registry"))]
+fn registries() -> Vec<YearRegistry> {
+  vec![]
+}
+
+fn current_year() -> u16 {
+  Zoned::now().year().try_into().unwrap()
 }
 
 fn main() -> Result<()> {
   let args = CommandLineArgs::parse();
-  let day = args.day;
-  let year = args.year.unwrap_or(Zoned::now().year().try_into().unwrap());
+
+  if let Some(Command::RunDay { year, day, run }) = &args.command {
+    let registries = registries();
+    let registry = registries
+      .iter()
+      .find(|r| r.year == *year)
+      .with_context(|| format!("{year} isn't compiled into this binary"))?;
+    return runner::run_registered_day(registry, *day, run);
+  }
+
+  let year = args.year.unwrap_or_else(current_year);
 
   let binding = MetadataCommand::new().exec().unwrap().workspace_root;
   let workspace_root = PathBuf::from(&binding);
 
-  bootstrap::setup(year, day, &workspace_root)
-    .with_context(|| format!("{RED} ✕ {BOLD}BOOTSTRAP FAILED{RESET}"))?;
-  runner::exec_day(year, day, &workspace_root)
+  if let Some(Command::Read { year, day, refresh }) = args.command {
+    let year = year.unwrap_or_else(current_year);
+    let markdown = bootstrap::read_description(year, day, &workspace_root, &args.account, refresh)
+      .with_context(|| format!("{RED} ✕ {BOLD}READ FAILED{RESET}"))?;
+    println!("{}", puzzle::render_markdown(&markdown));
+    return Ok(());
+  }
+
+  if let Some(Command::PerfReport {
+    year,
+    threshold,
+    against,
+    budget,
+  }) = args.command
+  {
+    let year = year.unwrap_or_else(current_year);
+    let budget = budget
+      .map(Duration::try_from_secs_f64)
+      .transpose()
+      .context("The budget should be a positive number of seconds")?;
+    let all_good = history::perf_report(year, &workspace_root, threshold, against, budget)
+      .with_context(|| format!("{RED} ✕ {BOLD}PERF REPORT FAILED{RESET}"))?;
+    if !all_good {
+      bail!("{RED} ✕ {BOLD}Some parts got slower, or the year is over budget{RESET}");
+    }
+    return Ok(());
+  }
+
+  if let Some(Command::Submit {
+    year,
+    day,
+    part,
+    answer,
+    base_url,
+  }) = args.command
+  {
+    let year = year.unwrap_or_else(current_year);
+    let client = http::AocClient::with_base_url(&args.account, &base_url)?;
+    let verdict = submit::submit(
+      year,
+      day,
+      part,
+      answer.as_deref(),
+      &workspace_root,
+      &client,
+      &args.account,
+    )
+    .with_context(|| format!("{RED} ✕ {BOLD}SUBMIT FAILED{RESET}"))?;
+    println!("{verdict}");
+    if verdict != submit::Verdict::Correct {
+      std::process::exit(1);
+    }
+    return Ok(());
+  }
+
+  let registries = registries();
+  let registry = registries.iter().find(|r| r.year == year);
+
+  if args.all || args.all_years {
+    let all_passed = if args.all_years {
+      runner::exec_all_years(&workspace_root, &args.run, &registries)
+    } else {
+      runner::exec_year(year, &workspace_root, &args.run, registry)
+    }
     .with_context(|| format!("{RED} ✕ {BOLD}RUNNER FAILED{RESET}"))?;
+    if !all_passed {
+      bail!("{RED} ✕ {BOLD}Some days did not pass{RESET}");
+    }
+    return Ok(());
+  }
+
+  let day = args.day.unwrap();
+  bootstrap::setup(year, day, &workspace_root, &args.account)
+    .with_context(|| format!("{RED} ✕ {BOLD}BOOTSTRAP FAILED{RESET}"))?;
+  if args.watch {
+    // Code compiled into this binary can't change, so watching always runs the day as a child process.
+    return watch::watch_day(year, day, &workspace_root, &args.run)
+      .with_context(|| format!("{RED} ✕ {BOLD}WATCH FAILED{RESET}"));
+  }
+  // A day that was just created by the bootstrap won't be in the registry yet.
+  match registry.filter(|r| r.get(day).is_some()) {
+    Some(registry) => runner::run_registered_day(registry, day, &args.run),
+    None => runner::exec_day(year, day, &workspace_root, &args.run),
+  }
+  .with_context(|| format!("{RED} ✕ {BOLD}RUNNER FAILED{RESET}"))?;
   Ok(())
 }